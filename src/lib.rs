@@ -1,2 +1,3 @@
 // The top-level package for my AOC 2022 solutions repository.
+pub mod solutions;
 pub mod utils;
@@ -0,0 +1,44 @@
+/// Parsed form of the AOC 2022 Day 1 input used by the solver functions.
+pub type ProblemInput = Vec<Vec<u64>>;
+
+/// Parses the raw AOC 2022 Day 1 input in the format required by the solver functions.
+/// Returned value is vector containing vectors with the calorie values for each elf.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    let elf_splits = raw_input.split("\n\n");
+    let mut elf_packs: Vec<Vec<u64>> = vec![];
+    for elf_split in elf_splits {
+        let elf_calories = elf_split
+            .trim()
+            .split('\n')
+            .map(|x| x.trim().parse::<u64>().unwrap())
+            .collect::<Vec<u64>>();
+        elf_packs.push(elf_calories);
+    }
+    elf_packs
+}
+
+/// Solves both parts of AOC 2022 Day 1 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day01_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 1 Part 1 // Returns the maximum total calories across each of the elf packs.
+pub fn solve_part1(elf_packs: &[Vec<u64>]) -> u64 {
+    elf_packs.iter().map(|x| x.iter().sum()).max().unwrap()
+}
+
+/// Solves AOC 2022 Day 1 Part 2 // Returns the total calories for the elf packs with the top three
+/// calorie totals.
+pub fn solve_part2(elf_packs: &[Vec<u64>]) -> u64 {
+    let mut sums = elf_packs
+        .iter()
+        .map(|x| x.iter().sum())
+        .collect::<Vec<u64>>();
+    sums.sort();
+    sums.iter().rev().take(3).sum()
+}
@@ -0,0 +1,66 @@
+/// Parsed form of the AOC 2022 Day 2 input used by the solver functions.
+pub type ProblemInput = Vec<String>;
+
+/// Parses the raw AOC 2022 Day 2 input in the format required by the solver functions.
+/// Returned value is vector of strings extracted from the lines of the input file.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    raw_input
+        .trim()
+        .lines()
+        .map(|line| String::from(line.trim()))
+        .collect::<Vec<String>>()
+}
+
+/// Solves both parts of AOC 2022 Day 2 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day02_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 2 Part 1 // Returns the total score from playing the "rock paper scissors"
+/// game, assuming everything goes exactly according to the strategy guide.
+pub fn solve_part1(rounds: &[String]) -> u64 {
+    let mut total_score = 0;
+    for line in rounds {
+        // A / X: rock (1), B / Y: paper (2), C / Z: scissors (3), lose (0), draw (3), win (6)
+        total_score += match line.as_str() {
+            "A X" => 4, // draw (3, 1)
+            "A Y" => 8, // win (6, 2)
+            "A Z" => 3, // lose (0, 3)
+            "B X" => 1, // lose (0, 1)
+            "B Y" => 5, // draw (3, 2)
+            "B Z" => 9, // win (6, 3)
+            "C X" => 7, // win (6, 1)
+            "C Y" => 2, // lose (0, 2)
+            "C Z" => 6, // draw (3, 3)
+            _ => panic!("Day 2 Part 1 - bad round pattern!"),
+        }
+    }
+    total_score
+}
+
+/// Solves AOC 2022 Day 2 Part 2 // Returns the total score from playing the "rock paper scissors"
+/// game, with the second second shape representing the required outcome from the round.
+pub fn solve_part2(rounds: &[String]) -> u64 {
+    let mut total_score = 0;
+    for line in rounds {
+        // A: rock (1), B: paper (2), C: scissors (3), X: lose (0), Y: draw (3), Z: win (6)
+        total_score += match line.as_str() {
+            "A X" => 3, // lose (0, 3)
+            "A Y" => 4, // draw (3, 1)
+            "A Z" => 8, // win (6, 2)
+            "B X" => 1, // lose (0, 1)
+            "B Y" => 5, // draw (3, 2)
+            "B Z" => 9, // win (6, 3)
+            "C X" => 2, // lose (0, 2)
+            "C Y" => 6, // draw (3, 3)
+            "C Z" => 7, // win (6, 1)
+            _ => panic!("Day 2 Part 2 - bad round pattern!"),
+        }
+    }
+    total_score
+}
@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+/// Parsed form of the AOC 2022 Day 3 input used by the solver functions.
+pub type ProblemInput = Vec<String>;
+
+/// Parses the raw AOC 2022 Day 3 input in the format required by the solver functions.
+/// Returned value is vector or strings extracted from the lines of the input file.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    raw_input
+        .trim()
+        .lines()
+        .map(|line| String::from(line.trim()))
+        .collect::<Vec<String>>()
+}
+
+/// Solves both parts of AOC 2022 Day 3 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day03_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 3 Part 1 // Returns the total prioritisation of the items in both
+/// compartments for all knapsacks.
+pub fn solve_part1(input: &[String]) -> u64 {
+    let mut total = 0;
+    for items in input {
+        let first = items[0..items.len() / 2].chars().collect::<HashSet<char>>();
+        let last = items[items.len() / 2..].chars().collect::<HashSet<char>>();
+        let common_item = *first.intersection(&last).next().unwrap();
+        if common_item.is_lowercase() {
+            total += 1 + (common_item as u64 - 'a' as u64);
+        } else {
+            total += 27 + (common_item as u64 - 'A' as u64);
+        }
+    }
+    total
+}
+
+/// Solves AOC 2022 Day 3 Part 2 // Returns the total prioritisation of the common item between each
+/// three elf group.
+pub fn solve_part2(input: &[String]) -> u64 {
+    let mut total = 0;
+    for i in (0..input.len()).step_by(3) {
+        // Find intersection of
+        let first = input[i].chars().collect::<HashSet<char>>();
+        let second = input[i + 1].chars().collect::<HashSet<char>>();
+        let third = input[i + 2].chars().collect::<HashSet<char>>();
+        // Intersection of first two sets
+        let first_second = first
+            .intersection(&second)
+            .copied()
+            .collect::<HashSet<char>>();
+        // Intersection of third set with first two sets
+        let common_item = *first_second.intersection(&third).next().unwrap();
+        if common_item.is_lowercase() {
+            total += 1 + (common_item as u64 - 'a' as u64);
+        } else {
+            total += 27 + (common_item as u64 - 'A' as u64);
+        }
+    }
+    total
+}
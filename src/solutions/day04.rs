@@ -0,0 +1,103 @@
+use regex::Regex;
+
+/// Parsed form of the AOC 2022 Day 4 input used by the solver functions.
+pub type ProblemInput = Vec<(u64, u64, u64, u64)>;
+
+/// Parses the raw AOC 2022 Day 4 input in the format required by the solver functions.
+/// Returned value is vector of four-tuples containing the lower and upper limits of the ranges
+/// specified in the lines of the input file.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    let regex_line = Regex::new(r"^(\d+)-(\d+),(\d+)-(\d+)$").unwrap();
+    let mut ranges: Vec<(u64, u64, u64, u64)> = vec![];
+    for line in raw_input.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let captures = regex_line.captures(line).unwrap();
+        let first_left = captures[1].parse::<u64>().unwrap();
+        let first_right = captures[2].parse::<u64>().unwrap();
+        let second_left = captures[3].parse::<u64>().unwrap();
+        let second_right = captures[4].parse::<u64>().unwrap();
+        ranges.push((first_left, first_right, second_left, second_right));
+    }
+    ranges
+}
+
+/// Solves both parts of AOC 2022 Day 4 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day04_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 4 Part 1 // Returns the number of range pairs where one range fully contains
+/// the other range.
+pub fn solve_part1(input: &[(u64, u64, u64, u64)]) -> u64 {
+    let mut count = 0;
+    for range_pair in input {
+        if check_for_whole_overlap(range_pair) {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Solves AOC 2022 Day 4 Part 2 // Returns the number of range pairs where the two ranges overlap
+/// in whole or in part
+pub fn solve_part2(input: &[(u64, u64, u64, u64)]) -> u64 {
+    let mut count = 0;
+    for range_pair in input {
+        if check_for_whole_overlap(range_pair) || check_for_partial_overlap(range_pair) {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Checks if one of the ranges in the pair fully contains the other.
+fn check_for_whole_overlap(range_pair: &(u64, u64, u64, u64)) -> bool {
+    let (first_left, first_right, second_left, second_right) = range_pair;
+    // Check if first range fully contains the second range
+    if first_left <= second_left && first_right >= second_right {
+        return true;
+    }
+    // Check if second range fully contains the first range
+    if second_left <= first_left && second_right >= first_right {
+        return true;
+    }
+    false
+}
+
+/// Checks if there is a partial overlap between the ranges.
+fn check_for_partial_overlap(range_pair: &(u64, u64, u64, u64)) -> bool {
+    // Calculate minimum length of range of the two ranges
+    let (first_left, first_right, second_left, second_right) = range_pair;
+    let first_len = first_right - first_left + 1;
+    let second_len = second_right - second_left + 1;
+    let min_len = {
+        if first_len < second_len {
+            first_len
+        } else {
+            second_len
+        }
+    };
+    // First range is to left
+    if first_left < second_right && first_right >= second_left {
+        let overlap_len = first_right - second_left + 1;
+        if overlap_len < min_len {
+            return true;
+        }
+    }
+    // Second range is to left
+    if second_left < first_right && second_right >= first_left {
+        let overlap_len = second_right - first_left + 1;
+        if overlap_len < min_len {
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,97 @@
+use std::collections::VecDeque;
+
+use regex::Regex;
+
+use crate::utils::structures::CrateStacks;
+
+/// Type defintion to simplify function signatures.
+pub type ProblemInput = (Vec<VecDeque<char>>, Vec<(usize, usize, usize)>);
+
+/// Parses the raw AOC 2022 Day 5 input in the format required by the solver functions. Returned
+/// value is tuple containing the vectors of crate stacks and move instructions.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    // Initialise the output structures
+    let mut stacks: Vec<VecDeque<char>> = vec![];
+    for _ in 0..9 {
+        stacks.push(VecDeque::<char>::new());
+    }
+    let mut move_instructions: Vec<(usize, usize, usize)> = vec![];
+    // Create the regexes
+    let stack_regex = Regex::new(concat!(
+        r#"^(    |\[[A-Z]\] )(    |\[[A-Z]\] )(    |\[[A-Z]\] )(    |\[[A-Z]\] )"#,
+        r#"(    |\[[A-Z]\] )(    |\[[A-Z]\] )(    |\[[A-Z]\] )(    |\[[A-Z]\] )"#,
+        r#"(   |\[[A-Z]\])$"#,
+    ))
+    .unwrap();
+    let crate_regex = Regex::new(r"\[([A-Z])\]").unwrap();
+    let move_regex = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+    // Process the lines
+    let mut line_count = 0;
+    for line in raw_input.lines() {
+        line_count += 1;
+        // Process crate stack lines
+        if (1..=8).contains(&line_count) {
+            let captures = stack_regex.captures(line).unwrap();
+            for i in 1..=9 {
+                if crate_regex.is_match(&captures[i]) {
+                    let c = crate_regex.captures(&captures[i]).unwrap()[1]
+                        .chars()
+                        .next()
+                        .unwrap();
+                    stacks[i - 1].push_front(c);
+                }
+            }
+        // Process move instruction lines
+        } else if line_count >= 11 {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let captures = move_regex.captures(line).unwrap();
+            let quantity = captures[1].parse::<usize>().unwrap();
+            let from = captures[2].parse::<usize>().unwrap() - 1;
+            let to = captures[3].parse::<usize>().unwrap() - 1;
+            move_instructions.push((quantity, from, to))
+        }
+    }
+    (stacks, move_instructions)
+}
+
+/// Solves both parts of AOC 2022 Day 5 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day05_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 5 Part 1 // Returns the crates at the top of each stack after processing
+/// the movement instructions.
+pub fn solve_part1(input: &ProblemInput) -> String {
+    operate_crane(input, false)
+}
+
+/// Solves AOC 2022 Day 5 Part 2 // Returns the crates at the top of each stack after processing
+/// the movement instructions, with the crane picking up and moving the crates at once rather than
+/// one-by-one.
+pub fn solve_part2(input: &ProblemInput) -> String {
+    operate_crane(input, true)
+}
+
+/// Processes the movement instructions on a copy of the initial crate stacks, then returns the
+/// crates at the top of each stack.
+fn operate_crane(input: &ProblemInput, preserve_order: bool) -> String {
+    let mut stacks = CrateStacks::new(
+        input
+            .0
+            .iter()
+            .map(|stack| stack.iter().copied().collect())
+            .collect(),
+    );
+    for (quantity, from, to) in input.1.iter() {
+        stacks.move_crates(*quantity, *from, *to, preserve_order);
+    }
+    stacks.tops()
+}
@@ -0,0 +1,93 @@
+use crate::utils::sequence::first_distinct_window;
+
+/// Parsed form of the AOC 2022 Day 6 input used by the solver functions.
+pub type ProblemInput = Vec<char>;
+
+/// Parses the raw Day 6 input into the vector of characters given in the input. Non-ASCII input is
+/// accepted, with each Unicode scalar value treated as a single character (so an accented letter
+/// such as 'é' is one character, distinct from 'e'). Characters built from combining sequences are
+/// split into their individual scalar values.
+pub fn parse_input(raw_input: &str) -> Vec<char> {
+    raw_input.trim().chars().collect::<Vec<char>>()
+}
+
+/// Solves both parts of AOC 2022 Day 6 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day06_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 6 Part 1 // Returns the number of characters that need to be processed
+/// before the first start-of-packet marker (four consecutive characters that are different) is
+/// observed.
+pub fn solve_part1(input: &[char]) -> usize {
+    if let Some(start) = first_distinct_window(input, 4) {
+        return start + 4;
+    }
+    panic!("Day 6 Part 1 - did not find the start-of-packet marker!");
+}
+
+/// Solves AOC 2022 Day 6 Part 2 // Returns the number of characters that need to be processed
+/// before the first start-of-message marker (14 consecutive characters that are different) is
+/// observed.
+pub fn solve_part2(input: &[char]) -> usize {
+    if let Some(start) = first_distinct_window(input, 14) {
+        return start + 14;
+    }
+    panic!("Day 6 Part 2 - did not find the start-of-message marker!");
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::utils::sequence::{find_marker, find_marker_in_iter};
+
+    use super::*;
+
+    /// Tests the string-based solver against the first example from the problem description.
+    #[test]
+    fn test_day06_solve_from_str() {
+        let solutions = solve_day06_from_str("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n");
+        assert_eq!((String::from("7"), String::from("19")), solutions);
+    }
+
+    /// Tests that non-ASCII characters are each treated as a single character that is distinct from
+    /// its unaccented form, with marker indices counted in characters rather than bytes.
+    #[test]
+    fn test_day06_non_ascii_input() {
+        let input = parse_input("éeéeéaéb\n");
+        assert_eq!(8, input.len());
+        assert_eq!(Some(2), find_marker(&input, 2));
+        assert_eq!(Some(6), find_marker(&input, 3));
+        assert_eq!(Some(6), find_marker_in_iter("éeéeéaéb".chars(), 3));
+    }
+
+    /// Tests that the streaming marker scanner gives the same index as the slice-based version when
+    /// fed the characters of the actual input and the examples from the problem description.
+    #[test]
+    fn test_day06_find_marker_in_iter() {
+        let raw_input = fs::read_to_string("./input/day06.txt").unwrap();
+        let examples = [
+            raw_input.trim(),
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+            "bvwbjplbgvbhsrlpgdmjqwftvncz",
+            "nppdvjthqldpwncqszvftbrmjlhg",
+            "aaaaaaaaaaaaaaaaaaaa",
+        ];
+        for example in examples {
+            let chars = example.chars().collect::<Vec<char>>();
+            for marker_len in [4, 14] {
+                assert_eq!(
+                    find_marker(&chars, marker_len),
+                    find_marker_in_iter(example.chars(), marker_len)
+                );
+            }
+        }
+        assert_eq!(Some(1109), find_marker_in_iter(raw_input.trim().chars(), 4));
+    }
+}
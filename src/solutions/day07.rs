@@ -0,0 +1,152 @@
+use std::collections::{HashMap, VecDeque};
+
+use regex::Regex;
+
+/// Represents a file or directory in a file system.
+pub enum FsItem {
+    File { size: usize },
+    Directory { parent_dir: String, name: String },
+}
+
+/// Parsed form of the AOC 2022 Day 7 input used by the solver functions.
+pub type ProblemInput = HashMap<String, Vec<FsItem>>;
+
+/// Parses the raw AOC 2022 Day 7 input in the format required by the solver functions.
+/// Returned value is hashmap containing each directory (full path name) mapped to the vector of
+/// fsitems contained in the directory.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    let mut output: HashMap<String, Vec<FsItem>> = HashMap::new();
+    let mut current_dir: VecDeque<String> = VecDeque::new();
+    let cd_regex = Regex::new(r"^[$] cd (.*)$").unwrap();
+    let file_regex = Regex::new(r"^(\d+) (.*)$").unwrap();
+    let dir_regex = Regex::new(r"^dir (.*)$").unwrap();
+    let lines = raw_input
+        .trim()
+        .lines()
+        .map(|line| line.trim().to_string())
+        .collect::<Vec<String>>();
+    let mut cursor = 0;
+    loop {
+        if cursor >= lines.len() {
+            break;
+        }
+        if cd_regex.is_match(&lines[cursor]) {
+            let caps = cd_regex.captures(&lines[cursor]).unwrap();
+            let dir = caps[1].to_string();
+            if dir == ".." {
+                current_dir.pop_back();
+                cursor += 1;
+            } else if dir == "/" {
+                current_dir.push_back(dir);
+                let cwd = current_dir
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<String>>()
+                    .join("");
+                output.insert(cwd, vec![]);
+                cursor += 2;
+            } else {
+                current_dir.push_back(format!("{}/", dir));
+                let cwd = current_dir
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<String>>()
+                    .join("");
+                output.insert(cwd, vec![]);
+                cursor += 2;
+            }
+        } else {
+            let cwd = current_dir
+                .iter()
+                .cloned()
+                .collect::<Vec<String>>()
+                .join("");
+            loop {
+                if cursor >= lines.len() || cd_regex.is_match(&lines[cursor]) {
+                    break;
+                } else if file_regex.is_match(&lines[cursor]) {
+                    let caps = file_regex.captures(&lines[cursor]).unwrap();
+                    let size = caps[1].parse::<usize>().unwrap();
+                    let file_item = FsItem::File { size };
+                    output.get_mut(&cwd).unwrap().push(file_item);
+                } else if dir_regex.is_match(&lines[cursor]) {
+                    let caps = dir_regex.captures(&lines[cursor]).unwrap();
+                    let name = caps[1].to_string();
+                    let dir_item = FsItem::Directory {
+                        parent_dir: cwd.to_string(),
+                        name,
+                    };
+                    output.get_mut(&cwd).unwrap().push(dir_item);
+                } else {
+                    panic!("Day 7 - bad file system item!");
+                }
+                cursor += 1;
+            }
+        }
+    }
+    output
+}
+
+/// Solves both parts of AOC 2022 Day 7 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day07_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 7 Part 1 // Calculates the total size of all directories that have a size of
+/// at most 100,000.
+pub fn solve_part1(dirs: &HashMap<String, Vec<FsItem>>) -> usize {
+    let mut dir_sizes: HashMap<String, usize> = HashMap::new();
+    find_dir_sizes(dirs, &mut dir_sizes, &String::from("/"));
+    dir_sizes
+        .values()
+        .copied()
+        .filter(|size| *size <= 100000)
+        .sum()
+}
+
+/// Solves AOC 2022 Day 7 Part 2 // Finds the size of the smallest directory that would free up
+/// enough space if deleted.
+pub fn solve_part2(dirs: &HashMap<String, Vec<FsItem>>) -> usize {
+    let max_fs_size: usize = 70000000;
+    let req_free_space: usize = 30000000;
+    let mut dir_sizes: HashMap<String, usize> = HashMap::new();
+    find_dir_sizes(dirs, &mut dir_sizes, &String::from("/"));
+    let free_space = max_fs_size - dir_sizes.get("/").unwrap();
+    // Calculate extra amount of free space
+    let delta = req_free_space - free_space;
+    let mut candidate_dirs = dir_sizes
+        .values()
+        .copied()
+        .filter(|size| *size >= delta)
+        .collect::<Vec<usize>>();
+    candidate_dirs.sort();
+    candidate_dirs[0]
+}
+
+/// Finds the sizes of all directories below the given dir. Size of directories are updated into the
+/// dir_sizes hashmap.
+fn find_dir_sizes(
+    dirs: &HashMap<String, Vec<FsItem>>,
+    dir_sizes: &mut HashMap<String, usize>,
+    dir: &String,
+) {
+    let mut total_size = 0;
+    for fs_item in dirs.get(dir).unwrap().iter() {
+        match fs_item {
+            FsItem::Directory { parent_dir, name } => {
+                let cwd = format!("{}{}/", parent_dir, name);
+                find_dir_sizes(dirs, dir_sizes, &cwd);
+                total_size += dir_sizes.get(&cwd).unwrap();
+            }
+            FsItem::File { size } => {
+                total_size += size;
+            }
+        }
+    }
+    dir_sizes.insert(dir.to_string(), total_size);
+}
@@ -0,0 +1,162 @@
+/// Parsed form of the AOC 2022 Day 8 input used by the solver functions.
+pub type ProblemInput = Vec<Vec<u64>>;
+
+/// Parses the raw AOC 2022 Day 8 input in the format required by the solver functions.
+/// Returned value is 2d vector of values representing tree heights given in the input file.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    raw_input
+        .trim()
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| c.to_digit(10).unwrap() as u64)
+                .collect::<Vec<u64>>()
+        })
+        .collect::<Vec<Vec<u64>>>()
+}
+
+/// Solves both parts of AOC 2022 Day 8 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day08_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 8 Part 1 // Calculates the number of trees that are visible from outside the
+/// grid.
+pub fn solve_part1(tree_heights: &[Vec<u64>]) -> usize {
+    let max_y = tree_heights.len() - 1;
+    let max_x = tree_heights[0].len() - 1;
+    let mut total_visible = 0;
+    for y in 0..=max_y {
+        for x in 0..=max_x {
+            // Check if tree is on the edge of the grid
+            if y == 0 || y == max_y {
+                total_visible += 1;
+                continue;
+            }
+            if x == 0 || x == max_x {
+                total_visible += 1;
+                continue;
+            }
+            // Check if current tree is visible from one side
+            if check_left_side_visibility(x, y, tree_heights)
+                || check_top_side_visibility(x, y, tree_heights)
+                || check_right_side_visibility(x, y, tree_heights)
+                || check_bottom_side_visibility(x, y, tree_heights)
+            {
+                total_visible += 1;
+            }
+        }
+    }
+    total_visible
+}
+
+/// Solves AOC 2022 Day 8 Part 2 // Calculates the highest "scenic score" possible from any tree.
+pub fn solve_part2(tree_heights: &[Vec<u64>]) -> usize {
+    let max_y = tree_heights.len() - 1;
+    let max_x = tree_heights[0].len() - 1;
+    let mut max_scenic_score = 0;
+    for y in 0..=max_y {
+        for x in 0..=max_x {
+            // Calculate scenic score from product of LEFT, TOP, RIGHT and BOTTOM viewing distances
+            let mut scenic_score = 1;
+            scenic_score *= get_left_side_viewing_distance(x, y, tree_heights);
+            scenic_score *= get_top_side_viewing_distance(x, y, tree_heights);
+            scenic_score *= get_right_side_viewing_distance(x, y, tree_heights);
+            scenic_score *= get_bottom_side_viewing_distance(x, y, tree_heights);
+            // Calculate scenic score and check if it is the new maximum value
+            if scenic_score > max_scenic_score {
+                max_scenic_score = scenic_score;
+            }
+        }
+    }
+    max_scenic_score
+}
+
+/// Checks if the current tree is visible from the LEFT side of the grid.
+fn check_left_side_visibility(x: usize, y: usize, tree_heights: &[Vec<u64>]) -> bool {
+    for new_x in 0..x {
+        if tree_heights[y][new_x] >= tree_heights[y][x] {
+            return false;
+        }
+    }
+    true
+}
+
+/// Checks if the current tree is visible from the TOP side of the grid.
+fn check_top_side_visibility(x: usize, y: usize, tree_heights: &[Vec<u64>]) -> bool {
+    for new_y in 0..y {
+        if tree_heights[new_y][x] >= tree_heights[y][x] {
+            return false;
+        }
+    }
+    true
+}
+
+/// Checks if the current tree is visible from the RIGHT side of the grid.
+fn check_right_side_visibility(x: usize, y: usize, tree_heights: &[Vec<u64>]) -> bool {
+    let max_x = tree_heights[0].len() - 1;
+    for new_x in (x + 1)..=max_x {
+        if tree_heights[y][new_x] >= tree_heights[y][x] {
+            return false;
+        }
+    }
+    true
+}
+
+/// Checks if the current tree is visible from the BOTTOM side of the grid.
+fn check_bottom_side_visibility(x: usize, y: usize, tree_heights: &[Vec<u64>]) -> bool {
+    let max_y = tree_heights.len() - 1;
+    for new_y in (y + 1)..=max_y {
+        if tree_heights[new_y][x] >= tree_heights[y][x] {
+            return false;
+        }
+    }
+    true
+}
+
+/// Determines the LEFT side viewing distance from the current tree (x,y position).
+fn get_left_side_viewing_distance(x: usize, y: usize, tree_heights: &[Vec<u64>]) -> usize {
+    for i in 0..x {
+        if tree_heights[y][x - i - 1] >= tree_heights[y][x] {
+            return i + 1;
+        }
+    }
+    x
+}
+
+/// Determines the TOP side viewing distance from the current tree (x,y position).
+fn get_top_side_viewing_distance(x: usize, y: usize, tree_heights: &[Vec<u64>]) -> usize {
+    for i in 0..y {
+        if tree_heights[y - i - 1][x] >= tree_heights[y][x] {
+            return i + 1;
+        }
+    }
+    y
+}
+
+/// Determines the RIGHT side viewing distance from the current tree (x,y position).
+fn get_right_side_viewing_distance(x: usize, y: usize, tree_heights: &[Vec<u64>]) -> usize {
+    let max_x = tree_heights[0].len() - 1;
+    for i in 0..(max_x - x) {
+        if tree_heights[y][x + i + 1] >= tree_heights[y][x] {
+            return i + 1;
+        }
+    }
+    max_x - x
+}
+
+/// Determines the BOTTOM side viewing distance from the current tree (x,y position).
+fn get_bottom_side_viewing_distance(x: usize, y: usize, tree_heights: &[Vec<u64>]) -> usize {
+    let max_y = tree_heights.len() - 1;
+    for i in 0..(max_y - y) {
+        if tree_heights[y + i + 1][x] >= tree_heights[y][x] {
+            return i + 1;
+        }
+    }
+    max_y - y
+}
@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+
+use crate::utils::cartography::{CardinalDirection, Point2D};
+use crate::utils::parsing::parse_moves;
+
+/// Represents a movement in a different cardinal direction with an associated number of steps.
+#[derive(Debug)]
+pub enum MoveType {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Parsed form of the AOC 2022 Day 9 input used by the solver functions.
+pub type ProblemInput = Vec<(MoveType, usize)>;
+
+/// Parses the raw AOC 2022 Day 9 input in the format required by the solver functions.
+/// Returned value is vector of tuples containing move type and number of steps.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    let moves = parse_moves(raw_input).unwrap_or_else(|err| panic!("Day 9 - {}", err));
+    moves
+        .into_iter()
+        .map(|(dirn, steps)| {
+            let move_type = match dirn {
+                CardinalDirection::North => MoveType::Up,
+                CardinalDirection::East => MoveType::Right,
+                CardinalDirection::South => MoveType::Down,
+                CardinalDirection::West => MoveType::Left,
+            };
+            (move_type, steps as usize)
+        })
+        .collect::<Vec<(MoveType, usize)>>()
+}
+
+/// Solves both parts of AOC 2022 Day 9 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day09_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 9 Part 1 // Calculates the number of unique locations visited by the tail of
+/// the rope (two knots).
+pub fn solve_part1(instructions: &[(MoveType, usize)]) -> usize {
+    process_rope_moves(instructions, 2)
+}
+
+/// Solves AOC 2022 Day 9 Part 2 // Calculates the number of unique locations visited by the tail of
+/// the rope (10 knots).
+pub fn solve_part2(instructions: &[(MoveType, usize)]) -> usize {
+    process_rope_moves(instructions, 10)
+}
+
+/// Processes the rope moves and returns the number of unique locations visited by the tail knot.
+fn process_rope_moves(instructions: &[(MoveType, usize)], rope_len: usize) -> usize {
+    if rope_len == 0 {
+        return 0;
+    }
+    let mut tail_locs: HashSet<Point2D> = HashSet::new();
+    let mut knots: Vec<Point2D> = vec![Point2D::new(0, 0); rope_len].to_vec();
+    tail_locs.insert(knots[rope_len - 1]);
+    for (move_type, steps) in instructions {
+        for _ in 0..*steps {
+            // Move the first knot
+            let mut new_knots: Vec<Point2D> = vec![];
+            match move_type {
+                MoveType::Up => new_knots.push(knots[0].peek_move_point(0, -1)),
+                MoveType::Down => new_knots.push(knots[0].peek_move_point(0, 1)),
+                MoveType::Left => new_knots.push(knots[0].peek_move_point(-1, 0)),
+                MoveType::Right => new_knots.push(knots[0].peek_move_point(1, 0)),
+            }
+            // Now move the following knots
+            for i in 1..rope_len {
+                let delta_x = new_knots[i - 1].x() - knots[i].x();
+                let delta_y = new_knots[i - 1].y() - knots[i].y();
+                if delta_x.abs() >= 2 || delta_y.abs() >= 2 {
+                    // Normalise delta_x
+                    let dx = {
+                        if delta_x >= 2 {
+                            1
+                        } else if delta_x <= -2 {
+                            -1
+                        } else {
+                            0
+                        }
+                    };
+                    // Normalise delta_y
+                    let dy = {
+                        if delta_y >= 2 {
+                            1
+                        } else if delta_y <= -2 {
+                            -1
+                        } else {
+                            0
+                        }
+                    };
+                    new_knots.push(knots[i].peek_move_point(delta_x - dx, delta_y - dy));
+                } else {
+                    new_knots.push(knots[i]);
+                }
+            }
+            // Update the knot locations and insert the tail knot location into set
+            knots = new_knots;
+            tail_locs.insert(knots[rope_len - 1]);
+        }
+    }
+    tail_locs.len()
+}
@@ -0,0 +1,208 @@
+use std::collections::{HashMap, HashSet};
+
+use regex::Regex;
+
+/// Represents the different instructions for the display CPU.
+pub enum Instruction {
+    Noop,
+    Addx { value: i64 },
+}
+
+/// Parsed form of the AOC 2022 Day 10 input used by the solver functions.
+pub type ProblemInput = Vec<Instruction>;
+
+/// Parses the raw AOC 2022 Day 10 input in the format required by the solver functions.
+/// Returned value is vector of Instruction read from the lines of the input file..
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    let regex_noop = Regex::new(r"^noop$").unwrap();
+    let regex_addx = Regex::new(r"^addx (-?\d+)$").unwrap();
+    let mut output: Vec<Instruction> = vec![];
+    for line in raw_input.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        // Find the instruction type
+        if regex_noop.is_match(line) {
+            output.push(Instruction::Noop);
+        } else if regex_addx.is_match(line) {
+            let caps = regex_addx.captures(line).unwrap();
+            let value = caps[1].parse::<i64>().unwrap();
+            output.push(Instruction::Addx { value });
+        }
+    }
+    output
+}
+
+/// Solves both parts of AOC 2022 Day 10 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day10_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 10 Part 1 // Returns the sum of the signal strengths from the 20th, 60th,
+/// 100th, 140th, 180th and 220th clock cycle.
+pub fn solve_part1(input: &[Instruction]) -> i64 {
+    // Initialise register
+    let mut reg_x = 1;
+    let mut clock_cycle = 0; // clock cycle 0 is the 1st clock cycle
+    let mut target_cycles: HashSet<i64> = HashSet::from([19, 59, 99, 139, 179, 219]);
+    let mut signal_strength_sum = 0;
+    for instruct in input {
+        // Break early if all target cycles have been processed.
+        if target_cycles.is_empty() {
+            break;
+        }
+        // Process the instruction
+        match instruct {
+            Instruction::Noop => {
+                // Single cycle for noop
+                add_signal_strength_if_targeted(
+                    &mut target_cycles,
+                    clock_cycle,
+                    &mut signal_strength_sum,
+                    reg_x,
+                );
+                clock_cycle += 1;
+            }
+            Instruction::Addx { value } => {
+                // Two cycles for addx
+                for _ in 0..2 {
+                    add_signal_strength_if_targeted(
+                        &mut target_cycles,
+                        clock_cycle,
+                        &mut signal_strength_sum,
+                        reg_x,
+                    );
+                    clock_cycle += 1;
+                }
+                reg_x += value;
+            }
+        }
+    }
+    signal_strength_sum
+}
+
+/// Solves AOC 2022 Day 10 Part 2 // Determines the eight capital letters displayed on the CRT
+/// screen after processing the instructions.
+pub fn solve_part2(input: &[Instruction]) -> String {
+    let mut reg_x = 1;
+    let mut clock_cycle = 0;
+    // Output array (bool) where false represents dark pixel and true represents lit pixel
+    let mut output_array: [[bool; 40]; 6] = [[false; 40]; 6];
+    for instruct in input {
+        // Break when the whole CRT screen has been rendered
+        if clock_cycle > 240 {
+            break;
+        }
+        // Process the instruction
+        match instruct {
+            Instruction::Noop => {
+                // Single cycle for noop
+                draw_pixel_if_selected(reg_x, clock_cycle, &mut output_array);
+                clock_cycle += 1
+            }
+            Instruction::Addx { value } => {
+                // Two cycles for addx
+                for _ in 0..2 {
+                    draw_pixel_if_selected(reg_x, clock_cycle, &mut output_array);
+                    clock_cycle += 1
+                }
+                reg_x += value;
+            }
+        }
+    }
+    // Decode the output array
+    decode_output_array(&output_array)
+}
+
+/// Checks if a target clock cycle has been reached. If so, it is removed from the set of the target
+/// clock cycles and the current signal strength (product of clock cycle ordinal position and reg_x)
+/// is added to the output sum.
+fn add_signal_strength_if_targeted(
+    target_cycles: &mut HashSet<i64>,
+    clock_cycle: i64,
+    signal_strength_sum: &mut i64,
+    reg_x: i64,
+) {
+    if target_cycles.contains(&clock_cycle) {
+        *signal_strength_sum += reg_x * (clock_cycle + 1);
+        target_cycles.remove(&clock_cycle);
+    }
+}
+
+/// Checks if the pixel denoted by the current clock cycle is to be drawn (if the three-pixel
+/// sprite located by the reg_x value overlaps with the pixel denoted by the current clock cycle).
+/// If so, the corresponding pixel location in the output array is set to be true.
+fn draw_pixel_if_selected(reg_x: i64, clock_cycle: i64, output_array: &mut [[bool; 40]; 6]) {
+    if ((reg_x - 1)..=(reg_x + 1)).contains(&(clock_cycle % 40)) {
+        let x = (clock_cycle as usize) % 40;
+        let y = (clock_cycle as usize) / 40;
+        output_array[y][x] = true;
+    }
+}
+
+/// Decodes the output array by determining the eight capital letters represented in the array.
+fn decode_output_array(output_array: &[[bool; 40]; 6]) -> String {
+    // Output
+    let mut output = String::new();
+    // Letter sequences (6 rows of 5 chars all concatenated for each letter)
+    let letters: HashMap<&str, char> = HashMap::from([
+        (".##..#..#.#..#.####.#..#.#..#.", 'A'),
+        ("###..#..#.###..#..#.#..#.###..", 'B'),
+        (".###.#....#....#....#.....###.", 'C'),
+        ("###..#..#.#..#.#..#.#..#.###..", 'D'),
+        ("####.#....####.#....#....####.", 'E'),
+        ("####.#....###..#....#....#....", 'F'),
+        ("####.#..#.#....#.##.#..#.####.", 'G'),
+        ("#..#.#..#.####.#..#.#..#.#..#.", 'H'),
+        ("#####..#....#....#....#..#####", 'I'),
+        ("..##....#....#....#.#..#..##..", 'J'),
+        ("#..#.#.#..##...#.#..#.#..#..#.", 'K'),
+        ("#....#....#....#....#....####.", 'L'),
+        ("#...###.###.#.##...##...##...#", 'M'),
+        ("#...###..##.#.##..###...##...#", 'N'),
+        ("####.#..#.#..#.#..#.#..#.####.", 'O'),
+        ("###..#..#.#..#.###..#....#....", 'P'),
+        (".##..#..#.#..#.#..#..###.....#", 'Q'),
+        ("###..#..#.#..#.###..#.#..#..#.", 'R'),
+        (".###.#....#.....##.....#.###..", 'S'),
+        ("#####..#....#....#....#....#..", 'T'),
+        ("#..#.#..#.#..#.#..#.#..#..##..", 'U'),
+        ("#...##...##...##...#.#.#...#..", 'V'),
+        ("#...##...##.#.##.#.##.#.######", 'W'),
+        ("#...#.#.#...#....#...#.#.#...#", 'X'),
+        ("#...#.#.#...#....#....#....#..", 'Y'),
+        ("####....#...#...#...#....####.", 'Z'),
+    ]);
+    // Construct output for each of the eight character boxes (6px high and 5px wide)
+    for i in 0..8 {
+        let mut letter_key = String::new();
+        for row in output_array {
+            for x in 0..5 {
+                // Determine the char representation of the pixel from bool array
+                let pixel = {
+                    if row[x + i * 5] {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                };
+                // Push the pixel char to the end of the letter key
+                letter_key.push(pixel);
+            }
+        }
+        // Determine which capital letter is represented in the current character box
+        if letters.contains_key(letter_key.as_str()) {
+            output.push(*letters.get(letter_key.as_str()).unwrap());
+        } else {
+            output.push('#'); // Placeholder for invalid letter representation
+        }
+    }
+    // Return the result string
+    output
+}
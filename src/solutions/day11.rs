@@ -0,0 +1,212 @@
+use std::collections::VecDeque;
+
+use regex::Regex;
+
+use crate::utils::parsing::AocError;
+use crate::utils::wildlife::{
+    monkey_business, simulate_rounds, supermodulo, validate_targets, Monkey, Operation,
+};
+
+/// Parsed form of the AOC 2022 Day 11 input used by the solver functions.
+pub type ProblemInput = Vec<Monkey>;
+
+/// Parses the raw AOC 2022 Day 11 input in the format required by the solver functions.
+/// Returned value is vector of monkeys specified in the input file. Returns an error if a monkey
+/// description is malformed or describes a monkey that could not take part in the simulation.
+pub fn parse_input(raw_input: &str) -> Result<Vec<Monkey>, AocError> {
+    let mut output: Vec<Monkey> = vec![];
+    let regex_monkey = Regex::new(concat!(
+        r#"Monkey (\d+):%  Starting items: (.*)%  Operation: new = old (.*)%"#,
+        r#"  Test: divisible by (\d+)%    If true: throw to monkey (\d+)%"#,
+        r#"    If false: throw to monkey (\d+)"#
+    ))
+    .unwrap();
+    for split in raw_input
+        .trim()
+        .split("\n\n")
+        .map(|group| group.replace('\n', "%"))
+    {
+        let caps = regex_monkey.captures(&split).ok_or_else(|| {
+            AocError::Format(String::from("monkey does not match the expected format"))
+        })?;
+        let number_error = |_| AocError::Format(format!("bad number in monkey {}", &caps[1]));
+        // Extract starting items
+        let items: VecDeque<u64> = caps[2]
+            .split(", ")
+            .map(|value| value.parse::<u64>())
+            .collect::<Result<VecDeque<u64>, _>>()
+            .map_err(number_error)?;
+        // Determine operation
+        let op = {
+            if &caps[3] == "* old" {
+                Operation::Pow { value: 2 }
+            } else if let Some(value) = caps[3].strip_prefix("+ ") {
+                let value = value.parse::<u64>().map_err(number_error)?;
+                Operation::Add { value }
+            } else if let Some(value) = caps[3].strip_prefix("* ") {
+                let value = value.parse::<u64>().map_err(number_error)?;
+                Operation::Mult { value }
+            } else {
+                return Err(AocError::Format(format!(
+                    "bad operation in monkey {}",
+                    &caps[1]
+                )));
+            }
+        };
+        let test_mod = caps[4].parse::<u64>().map_err(number_error)?;
+        let true_monkey = caps[5].parse::<usize>().map_err(number_error)?;
+        let false_monkey = caps[6].parse::<usize>().map_err(number_error)?;
+        log::debug!(
+            "Day 11 - parsed monkey {} with {} starting items",
+            &caps[1],
+            items.len()
+        );
+        let monkey = Monkey::try_new(items, op, test_mod, true_monkey, false_monkey)?;
+        output.push(monkey);
+    }
+    validate_targets(&output)?;
+    Ok(output)
+}
+
+/// Solves both parts of AOC 2022 Day 11 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day11_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input).unwrap_or_else(|err| panic!("Day 11 - {}", err));
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 11 Part 1 // Calculates the resulting monkey business level after 20 rounds
+/// with worry reduction in place.
+pub fn solve_part1(initial_monkeys: &[Monkey]) -> u64 {
+    get_monkey_business(initial_monkeys, 20, true)
+}
+
+/// Solves AOC 2022 Day 11 Part 2 // Calculates the resulting monkey business level after 10,000
+/// rounds without worry reduction in place.
+pub fn solve_part2(initial_monkeys: &[Monkey]) -> u64 {
+    get_monkey_business(initial_monkeys, 10000, false)
+}
+
+/// Conducts a given number of rounds of monkey business, returning the product of the number of
+/// items inspected by the two busiest monkeys.
+fn get_monkey_business(initial_monkeys: &[Monkey], rounds: usize, reduce_worry: bool) -> u64 {
+    let monkeys = conduct_rounds(initial_monkeys, rounds, reduce_worry);
+    monkey_business(&monkeys)
+}
+
+/// Conducts a given number of rounds of monkey business, returning the resulting monkeys. This can
+/// be used to inspect the state of the monkeys after any number of rounds. If reduce worry is set,
+/// item worry levels are divided by 3 after each inspection.
+pub fn conduct_rounds(
+    initial_monkeys: &[Monkey],
+    rounds: usize,
+    reduce_worry: bool,
+) -> Vec<Monkey> {
+    let mut monkeys = initial_monkeys.to_owned();
+    let supermodulo = supermodulo(&monkeys);
+    log::info!(
+        "Day 11 - conducting {} rounds with {} monkeys (supermodulo {})",
+        rounds,
+        monkeys.len(),
+        supermodulo
+    );
+    let reduce = if reduce_worry { Some(3) } else { None };
+    simulate_rounds(&mut monkeys, rounds, reduce, supermodulo);
+    monkeys
+}
+
+#[cfg(test)]
+mod test {
+    use crate::utils::runner::load_test_input;
+    use crate::utils::wildlife::busiest_monkeys;
+
+    use super::*;
+
+    /// Tests the Day 11 Part 1 solver method against example input 001.
+    #[test]
+    fn test_day11_part1_t001() {
+        let input = parse_input(&load_test_input(11, "t001")).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(10605, solution);
+    }
+
+    /// Tests the Day 11 Part 2 solver method against example input 001.
+    #[test]
+    fn test_day11_part2_t001() {
+        let input = parse_input(&load_test_input(11, "t001")).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(2713310158, solution);
+    }
+
+    /// Tests that the two busiest monkeys after 20 rounds of example input 001 are monkeys 3 and 0.
+    #[test]
+    fn test_day11_busiest_monkeys_t001() {
+        let input = parse_input(&load_test_input(11, "t001")).unwrap();
+        let monkeys = conduct_rounds(&input, 20, true);
+        assert_eq!(vec![(3, 105), (0, 101)], busiest_monkeys(&monkeys, 2));
+    }
+
+    /// Tests that the state of the monkeys after the first round of example input 001 matches the
+    /// worked example.
+    #[test]
+    fn test_day11_snapshot_after_round1_t001() {
+        let input = parse_input(&load_test_input(11, "t001")).unwrap();
+        assert_eq!(Operation::Pow { value: 2 }, input[2].get_operation());
+        assert_eq!(
+            (1, 3),
+            (input[2].get_true_monkey(), input[2].get_false_monkey())
+        );
+        let monkeys = conduct_rounds(&input, 1, true);
+        let items = monkeys
+            .iter()
+            .map(|m| m.get_items().iter().copied().collect::<Vec<u64>>())
+            .collect::<Vec<Vec<u64>>>();
+        let expected: Vec<Vec<u64>> = vec![
+            vec![20, 23, 27, 26],
+            vec![2080, 25, 167, 207, 401, 1046],
+            vec![],
+            vec![],
+        ];
+        assert_eq!(expected, items);
+    }
+
+    /// Tests that malformed monkeys and monkeys throwing to a monkey outside of the group result in
+    /// format errors rather than panics.
+    #[test]
+    fn test_day11_parse_input_errors() {
+        let raw_input = load_test_input(11, "t001");
+        let bad_inputs = [
+            raw_input.replace("new = old * 19", "new = old / 19"),
+            raw_input.replace("divisible by 23", "divisible by 0"),
+            raw_input.replace("throw to monkey 3", "throw to monkey 4"),
+            raw_input.replace("Monkey 1:", "Monkey one:"),
+        ];
+        for bad_input in bad_inputs {
+            assert!(matches!(parse_input(&bad_input), Err(AocError::Format(_))));
+        }
+    }
+
+    /// Tests that the number of items inspected by each monkey without worry reduction matches the
+    /// worked example for example input 001, confirming that applying the supermodulo to the item
+    /// worry levels does not change where the items are thrown.
+    #[test]
+    fn test_day11_items_inspected_without_worry_reduction_t001() {
+        let input = parse_input(&load_test_input(11, "t001")).unwrap();
+        let cases: [(usize, [u64; 4]); 3] = [
+            (1, [2, 4, 3, 6]),
+            (20, [99, 97, 8, 103]),
+            (1000, [5204, 4792, 199, 5192]),
+        ];
+        for (rounds, expected) in cases {
+            let monkeys = conduct_rounds(&input, rounds, false);
+            let inspected = monkeys
+                .iter()
+                .map(|m| m.get_items_inspected())
+                .collect::<Vec<u64>>();
+            assert_eq!(expected.to_vec(), inspected);
+        }
+    }
+}
@@ -0,0 +1,383 @@
+use std::collections::{hash_map::Entry, HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+
+use crate::utils::cartography::{Grid, Point2D};
+
+/// Parsed form of the AOC 2022 Day 12 input used by the solver functions.
+pub type ProblemInput = (HashMap<Point2D, i64>, Point2D, Point2D);
+
+/// Describes a breadth-first search across the heightmap that did not reach its target.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SearchFailure {
+    /// Problem part that the search was conducted for (1 or 2).
+    pub part: u64,
+    /// Number of cells explored before the search ran out of points to visit.
+    pub explored: usize,
+}
+
+impl fmt::Display for SearchFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day 12 Part {} - did not reach the end point after exploring {} cells",
+            self.part, self.explored
+        )
+    }
+}
+
+impl Error for SearchFailure {}
+
+/// Parses the raw Day 12 input into the heightmap, start point and end point.
+pub fn parse_input(raw_input: &str) -> (HashMap<Point2D, i64>, Point2D, Point2D) {
+    let mut start: Option<Point2D> = None;
+    let mut end: Option<Point2D> = None;
+    let mut heightmap: HashMap<Point2D, i64> = HashMap::new();
+    let mut y = 0;
+    for line in raw_input.trim().lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        // Process the line of the heightmap
+        for (x, chr) in line.chars().enumerate() {
+            if chr == 'S' {
+                start = Some(Point2D::new(x as i64, y));
+                heightmap.insert(start.unwrap(), 0);
+            } else if chr == 'E' {
+                end = Some(Point2D::new(x as i64, y));
+                heightmap.insert(end.unwrap(), 25);
+            } else if chr.is_ascii_lowercase() {
+                let height = (chr as i64) - ('a' as i64);
+                heightmap.insert(Point2D::new(x as i64, y), height);
+            } else {
+                panic!("Day 12 - bad char in input file!");
+            }
+        }
+        y += 1;
+    }
+    (heightmap, start.unwrap(), end.unwrap())
+}
+
+/// Solves both parts of AOC 2022 Day 12 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day12_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 12 Part 1 // Determines the minimum number of steps needed to reach the end
+/// point from the start point.
+pub fn solve_part1(problem_input: &(HashMap<Point2D, i64>, Point2D, Point2D)) -> u64 {
+    let (heightmap, start, end) = problem_input;
+    get_min_steps_to_end(heightmap, start, end).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Solves AOC 2022 Day 12 Part 2 // Determines the minimum number of steps needed to reach the end
+/// point from a starting point with elevation 0.
+pub fn solve_part2(problem_input: &(HashMap<Point2D, i64>, Point2D, Point2D)) -> u64 {
+    let (heightmap, _, end) = problem_input;
+    get_min_steps_from_elevation0_to_end(heightmap, end).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Determines the minimum number of steps needed to reach the end point from the start point.
+/// Returns an error giving the number of cells explored if the end point cannot be reached.
+pub fn get_min_steps_to_end(
+    heightmap: &HashMap<Point2D, i64>,
+    start: &Point2D,
+    end: &Point2D,
+) -> Result<u64, SearchFailure> {
+    let mut visit_queue: VecDeque<(u64, Point2D)> = VecDeque::new();
+    visit_queue.push_back((0, *start));
+    let mut visited: HashSet<Point2D> = HashSet::new();
+    visited.insert(*start);
+    while !visit_queue.is_empty() {
+        // Get the current point to visit
+        let (steps, current_loc) = visit_queue.pop_front().unwrap();
+        if current_loc == *end {
+            return Ok(steps);
+        }
+        // Add the next points to visit
+        for valid_point in get_next_valid_points(heightmap, &current_loc, false) {
+            if !visited.contains(&valid_point) {
+                visit_queue.push_back((steps + 1, valid_point));
+                visited.insert(valid_point);
+            }
+        }
+    }
+    Err(SearchFailure {
+        part: 1,
+        explored: visited.len(),
+    })
+}
+
+/// Determines the minimum number of steps needed to reach a point with elevation 0 from the given
+/// starting point. Returns an error giving the number of cells explored if no point with elevation
+/// 0 can be reached.
+pub fn get_min_steps_from_elevation0_to_end(
+    heightmap: &HashMap<Point2D, i64>,
+    start: &Point2D,
+) -> Result<u64, SearchFailure> {
+    let mut visit_queue: VecDeque<(u64, Point2D)> = VecDeque::new();
+    visit_queue.push_back((0, *start));
+    let mut visited: HashSet<Point2D> = HashSet::new();
+    visited.insert(*start);
+    while !visit_queue.is_empty() {
+        // Get the current point to visit
+        let (steps, current_loc) = visit_queue.pop_front().unwrap();
+        if *heightmap.get(&current_loc).unwrap() == 0 {
+            return Ok(steps);
+        }
+        // Add the next points to visit
+        for valid_point in get_next_valid_points(heightmap, &current_loc, true) {
+            if !visited.contains(&valid_point) {
+                visit_queue.push_back((steps + 1, valid_point));
+                visited.insert(valid_point);
+            }
+        }
+    }
+    Err(SearchFailure {
+        part: 2,
+        explored: visited.len(),
+    })
+}
+
+/// Determines the minimum number of steps needed to reach every reachable point from the given
+/// starting point. If reverse course is set, the distances are calculated for travelling from each
+/// point to the starting point instead (as used when searching back from the end point).
+pub fn get_distance_map(
+    heightmap: &HashMap<Point2D, i64>,
+    start: &Point2D,
+    reverse_course: bool,
+) -> HashMap<Point2D, u64> {
+    let mut visit_queue: VecDeque<Point2D> = VecDeque::new();
+    visit_queue.push_back(*start);
+    let mut distances: HashMap<Point2D, u64> = HashMap::new();
+    distances.insert(*start, 0);
+    while let Some(current_loc) = visit_queue.pop_front() {
+        let steps = *distances.get(&current_loc).unwrap();
+        // Add the next points to visit
+        for valid_point in get_next_valid_points(heightmap, &current_loc, reverse_course) {
+            if let Entry::Vacant(e) = distances.entry(valid_point) {
+                e.insert(steps + 1);
+                visit_queue.push_back(valid_point);
+            }
+        }
+    }
+    distances
+}
+
+/// Determines the minimum number of steps needed to reach the end point from the start point using
+/// a bidirectional breadth-first search, expanding one full layer at a time from whichever side has
+/// the smaller frontier until the two searches meet. Returns None if the end point is unreachable.
+pub fn get_min_steps_to_end_bidirectional(
+    heightmap: &HashMap<Point2D, i64>,
+    start: &Point2D,
+    end: &Point2D,
+) -> Option<u64> {
+    if start == end {
+        return Some(0);
+    }
+    let mut forward_dist: HashMap<Point2D, u64> = HashMap::from([(*start, 0)]);
+    let mut backward_dist: HashMap<Point2D, u64> = HashMap::from([(*end, 0)]);
+    let mut forward_frontier: Vec<Point2D> = vec![*start];
+    let mut backward_frontier: Vec<Point2D> = vec![*end];
+    while !forward_frontier.is_empty() && !backward_frontier.is_empty() {
+        // Expand the smaller frontier, searching back from the end point with the reverse course
+        let reverse_course = backward_frontier.len() < forward_frontier.len();
+        let (frontier, dist, other_dist) = if reverse_course {
+            (&mut backward_frontier, &mut backward_dist, &forward_dist)
+        } else {
+            (&mut forward_frontier, &mut forward_dist, &backward_dist)
+        };
+        let mut next_frontier: Vec<Point2D> = vec![];
+        let mut best: Option<u64> = None;
+        for loc in frontier.iter() {
+            let steps = dist[loc] + 1;
+            for valid_point in get_next_valid_points(heightmap, loc, reverse_course) {
+                if let Entry::Vacant(e) = dist.entry(valid_point) {
+                    e.insert(steps);
+                    next_frontier.push(valid_point);
+                }
+                // Check for the searches meeting, completing the layer to find the shortest path
+                if let Some(other_steps) = other_dist.get(&valid_point) {
+                    let total = steps + other_steps;
+                    best = Some(best.map_or(total, |b| b.min(total)));
+                }
+            }
+        }
+        if best.is_some() {
+            return best;
+        }
+        *frontier = next_frontier;
+    }
+    None
+}
+
+/// Determines the shortest path from the start point to the end point, by recording the point each
+/// point was first reached from during the breadth-first search. Returned value is the points along
+/// the path, including the start and end points, or None if the end point is unreachable.
+pub fn get_shortest_path(
+    heightmap: &HashMap<Point2D, i64>,
+    start: &Point2D,
+    end: &Point2D,
+) -> Option<Vec<Point2D>> {
+    let mut visit_queue: VecDeque<Point2D> = VecDeque::from([*start]);
+    let mut came_from: HashMap<Point2D, Point2D> = HashMap::new();
+    let mut visited: HashSet<Point2D> = HashSet::from([*start]);
+    while let Some(current_loc) = visit_queue.pop_front() {
+        if current_loc == *end {
+            // Walk back from the end point to reconstruct the path
+            let mut path = vec![current_loc];
+            while let Some(prev) = came_from.get(path.last().unwrap()) {
+                path.push(*prev);
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for valid_point in get_next_valid_points(heightmap, &current_loc, false) {
+            if visited.insert(valid_point) {
+                came_from.insert(valid_point, current_loc);
+                visit_queue.push_back(valid_point);
+            }
+        }
+    }
+    None
+}
+
+/// Renders the heightmap to a string using the elevation letters ('a' to 'z'), with the points
+/// along the given path marked with '*'.
+pub fn render_path(heightmap: &HashMap<Point2D, i64>, path: &[Point2D]) -> String {
+    let mut cells: HashMap<Point2D, char> = heightmap
+        .iter()
+        .map(|(point, height)| (*point, (b'a' + *height as u8) as char))
+        .collect();
+    for point in path {
+        cells.insert(*point, '*');
+    }
+    Grid::from_points(&cells, ' ').render(|c| *c)
+}
+
+/// Gets the next valid points to visit from the current point.
+fn get_next_valid_points(
+    heightmap: &HashMap<Point2D, i64>,
+    loc: &Point2D,
+    reverse_course: bool,
+) -> Vec<Point2D> {
+    let elevation = heightmap[loc];
+    loc.get_orthogonal_points_filtered(|check_loc| match heightmap.get(check_loc) {
+        // Reverse course checks the elevation change for travelling to the current location
+        Some(check_elevation) if reverse_course => elevation - check_elevation <= 1,
+        Some(check_elevation) => check_elevation - elevation <= 1,
+        None => false,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::utils::runner::load_test_input;
+
+    use super::*;
+
+    /// Tests the string-based solver against the contents of example input 001.
+    #[test]
+    fn test_day12_solve_from_str() {
+        let solutions = solve_day12_from_str(&load_test_input(12, "t001"));
+        assert_eq!((String::from("31"), String::from("29")), solutions);
+    }
+
+    /// Tests that the shortest path for example input 001 has the Part 1 number of steps, and that
+    /// the number of cells marked in the rendered heightmap matches the length of the path.
+    #[test]
+    fn test_day12_render_path_t001() {
+        let (heightmap, start, end) = parse_input(&load_test_input(12, "t001"));
+        let path = get_shortest_path(&heightmap, &start, &end).unwrap();
+        assert_eq!(31, path.len() - 1);
+        assert_eq!((Some(&start), Some(&end)), (path.first(), path.last()));
+        let rendered = render_path(&heightmap, &path);
+        assert_eq!(path.len(), rendered.chars().filter(|c| *c == '*').count());
+        assert_eq!(5, rendered.lines().count());
+        assert!(rendered.lines().all(|line| line.len() == 8));
+    }
+
+    /// Tests that the searches report the number of cells explored when the target cannot be
+    /// reached.
+    #[test]
+    fn test_day12_unreachable_end_reports_explored_cells() {
+        let (heightmap, start, end) = parse_input("abSc\nzzzz\nzzzE\n");
+        let err = get_min_steps_to_end(&heightmap, &start, &end).unwrap_err();
+        assert_eq!(
+            SearchFailure {
+                part: 1,
+                explored: 3
+            },
+            err
+        );
+        assert!(err.to_string().contains("exploring 3 cells"));
+        let err = get_min_steps_from_elevation0_to_end(&heightmap, &end).unwrap_err();
+        assert_eq!(
+            SearchFailure {
+                part: 2,
+                explored: 8
+            },
+            err
+        );
+        assert!(err.to_string().contains("Part 2"));
+    }
+
+    /// Tests that the distance map from the start point has the start at distance 0 and the end
+    /// point at the distance found by the Part 1 solver, and that the reverse distance map from the
+    /// end point gives the Part 2 solution, for example input 001 and the actual input.
+    #[test]
+    fn test_day12_get_distance_map() {
+        let inputs = [
+            (parse_input(&load_test_input(12, "t001")), 31, 29),
+            (
+                parse_input(&fs::read_to_string("./input/day12.txt").unwrap()),
+                352,
+                345,
+            ),
+        ];
+        for ((heightmap, start, end), part1, part2) in inputs {
+            let distances = get_distance_map(&heightmap, &start, false);
+            assert_eq!(Some(&0), distances.get(&start));
+            assert_eq!(Some(&part1), distances.get(&end));
+            let reverse_distances = get_distance_map(&heightmap, &end, true);
+            let min_from_elevation0 = reverse_distances
+                .iter()
+                .filter(|(point, _)| heightmap[point] == 0)
+                .map(|(_, steps)| *steps)
+                .min();
+            assert_eq!(Some(part2), min_from_elevation0);
+        }
+    }
+
+    /// Tests that the bidirectional search gives the same minimum number of steps as the forward
+    /// search for example input 001 and the actual input.
+    #[test]
+    fn test_day12_get_min_steps_to_end_bidirectional() {
+        let (heightmap, start, end) = parse_input(&load_test_input(12, "t001"));
+        let steps = get_min_steps_to_end_bidirectional(&heightmap, &start, &end);
+        assert_eq!(Some(31), steps);
+        let (heightmap, start, end) =
+            parse_input(&fs::read_to_string("./input/day12.txt").unwrap());
+        let steps = get_min_steps_to_end_bidirectional(&heightmap, &start, &end);
+        assert_eq!(Some(352), steps);
+    }
+
+    /// Tests that the Part 1 solution for the actual input can be found using the breadth-first
+    /// search provided by the grid.
+    #[test]
+    fn test_day12_part1_actual_grid_bfs() {
+        let (heightmap, start, end) =
+            parse_input(&fs::read_to_string("./input/day12.txt").unwrap());
+        let grid = Grid::from_points(&heightmap, i64::MAX);
+        let distances = grid.bfs_from(start, |from, to| to - from <= 1);
+        assert_eq!(Some(&352), distances.get(&end));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::utils::structures::NestedList;
+
+/// Parsed form of the AOC 2022 Day 13 input used by the solver functions.
+pub type ProblemInput = Vec<(NestedList, NestedList)>;
+
+/// Parses the raw AOC 2022 Day 13 input in the format required by the solver functions.
+/// Returned value is vector of packet pairs given in the input file.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    let mut output: Vec<(NestedList, NestedList)> = vec![];
+    for pair in raw_input.trim().split("\n\n") {
+        let packets = pair
+            .lines()
+            .map(|line| line.parse::<NestedList>().unwrap())
+            .collect::<Vec<NestedList>>();
+        output.push((packets[0].clone(), packets[1].clone()));
+    }
+    output
+}
+
+/// Solves both parts of AOC 2022 Day 13 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day13_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 13 Part 1 // Returns the sum of the pair indices for the pairs that are in
+/// the correct order.
+pub fn solve_part1(input: &[(NestedList, NestedList)]) -> usize {
+    input
+        .iter()
+        .enumerate()
+        .filter(|(_, (left, right))| left < right)
+        .map(|(i, _)| i + 1)
+        .sum()
+}
+
+/// Solves AOC 2022 Day 13 Part 2 // Determines the decoder key for the distress signal.
+pub fn solve_part2(input: &[(NestedList, NestedList)]) -> usize {
+    // Add all packets into the vector, along with the divider packets
+    let divider2 = "[[2]]".parse::<NestedList>().unwrap();
+    let divider6 = "[[6]]".parse::<NestedList>().unwrap();
+    let mut packets: Vec<&NestedList> = input.iter().flat_map(|(l, r)| [l, r]).collect();
+    packets.push(&divider2);
+    packets.push(&divider6);
+    // Sort the packets, then get the indices of the divider packets
+    packets.sort();
+    let index2 = packets.iter().position(|p| **p == divider2);
+    let index6 = packets.iter().position(|p| **p == divider6);
+    match (index2, index6) {
+        (Some(index2), Some(index6)) => (index2 + 1) * (index6 + 1),
+        _ => panic!("Day 13 Part 2 - could not find both divider packets in sorted vector!"),
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use crate::utils::cartography::Point2D;
+
+/// Represents a single tile type in the cave map.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum TileType {
+    Rock,
+    Sand,
+}
+
+/// Parsed form of the AOC 2022 Day 14 input used by the solver functions.
+pub type ProblemInput = HashMap<Point2D, TileType>;
+
+/// Parses the raw AOC 2022 Day 14 input in the format required by the solver functions.
+/// Returned value is hashmap representing the locations of cave rock specified in the input file.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    let mut cave_map: HashMap<Point2D, TileType> = HashMap::new();
+    for line in raw_input.lines() {
+        // Trim line and skip if empty
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        // Split the line into points in the rock segments
+        let mut points: Vec<(i64, i64)> = vec![];
+        for point_raw in line.split(" -> ") {
+            let pair = point_raw
+                .split(',')
+                .map(|x| x.parse::<i64>().unwrap())
+                .collect::<Vec<i64>>();
+            points.push((pair[0], pair[1]));
+        }
+        // Draw the rock segments
+        for i in 1..points.len() {
+            // Determine the end points of the rock segments
+            let x_vals = [points[i - 1].0, points[i].0];
+            let y_vals = [points[i - 1].1, points[i].1];
+            let x_from = *x_vals.iter().min().unwrap();
+            let y_from = *y_vals.iter().min().unwrap();
+            let x_to = *x_vals.iter().max().unwrap();
+            let y_to = *y_vals.iter().max().unwrap();
+            // Check if the rock segment is horizontal or vertical
+            if x_from == x_to {
+                for y in y_from..=y_to {
+                    cave_map.insert(Point2D::new(x_from, y), TileType::Rock);
+                }
+            } else if y_from == y_to {
+                for x in x_from..=x_to {
+                    cave_map.insert(Point2D::new(x, y_from), TileType::Rock);
+                }
+            } else {
+                panic!("Day 14 - bad element in cave map line!");
+            }
+        }
+    }
+    cave_map
+}
+
+/// Solves both parts of AOC 2022 Day 14 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day14_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 14 Part 1 // Determines the number of units of sand that come to rest before
+/// sand falls into the abyss.
+pub fn solve_part1(input: &HashMap<Point2D, TileType>) -> usize {
+    simulate_cave_sand_falling(input, false)
+}
+
+/// Solves AOC 2022 Day 14 Part 2 // Determines the number of units of sand that come to rest when
+/// the cave floor is included.
+pub fn solve_part2(input: &HashMap<Point2D, TileType>) -> usize {
+    simulate_cave_sand_falling(input, true)
+}
+
+/// Simulates the sand falling into the cave, starting at (x,y):(500,0). Returns the number of units
+/// of sand that come to rest.
+fn simulate_cave_sand_falling(input: &HashMap<Point2D, TileType>, include_floor: bool) -> usize {
+    let mut cave_map = input.clone();
+    let max_y = cave_map.keys().map(|loc| loc.y()).max().unwrap();
+    let sand_origin = Point2D::new(500, 0);
+    loop {
+        let mut sand_loc = sand_origin;
+        let mut reached_base_case = false;
+        loop {
+            // Check if the sand is in the abyss
+            if !include_floor && sand_loc.y() > max_y {
+                reached_base_case = true;
+                break;
+            }
+            if include_floor && sand_loc.y() == max_y + 1 {
+                cave_map.insert(sand_loc, TileType::Sand);
+                break;
+            }
+            // Check where the sand moves to
+            if !cave_map.contains_key(&sand_loc.peek_move_point(0, 1)) {
+                // Try to move directly down
+                sand_loc.move_point(0, 1);
+                continue;
+            } else if !cave_map.contains_key(&sand_loc.peek_move_point(-1, 1)) {
+                // Try to move down diag left
+                sand_loc.move_point(-1, 1);
+                continue;
+            } else if !cave_map.contains_key(&sand_loc.peek_move_point(1, 1)) {
+                // Try to move down diag right
+                sand_loc.move_point(1, 1);
+                continue;
+            } else {
+                // Sand comes to rest
+                cave_map.insert(sand_loc, TileType::Sand);
+                if include_floor && sand_loc == sand_origin {
+                    reached_base_case = true;
+                }
+                break;
+            }
+        }
+        // Check if base case has been reached - return the number of sand units at rest
+        if reached_base_case {
+            return cave_map
+                .values()
+                .copied()
+                .filter(|tile| *tile == TileType::Sand)
+                .count();
+        }
+    }
+}
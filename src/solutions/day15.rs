@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use regex::Regex;
+
+use crate::utils::cartography::Point2D;
+
+const PART1_TARGET_ROW: i64 = 2000000;
+const PART2_ROW_LIMIT: i64 = 4000000;
+
+/// Parsed form of the AOC 2022 Day 15 input used by the solver functions.
+pub type ProblemInput = Vec<(Point2D, Point2D)>;
+
+/// Parses the raw AOC 2022 Day 15 input in the format required by the solver functions.
+/// Returned value is vector of tuples containing the sensor locations and the location of their
+/// closest beacon.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    let mut output: Vec<(Point2D, Point2D)> = vec![];
+    let regex_line =
+        Regex::new(r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$")
+            .unwrap();
+    for line in raw_input.lines() {
+        // Trim input file line and skip over empty lines
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        // Extract fields from input file line
+        let caps = regex_line.captures(line).unwrap();
+        let x_sens = caps[1].parse::<i64>().unwrap();
+        let y_sens = caps[2].parse::<i64>().unwrap();
+        let x_beac = caps[3].parse::<i64>().unwrap();
+        let y_beac = caps[4].parse::<i64>().unwrap();
+        // Create points for the sensor and beacon locations
+        let loc_sens = Point2D::new(x_sens, y_sens);
+        let loc_beac = Point2D::new(x_beac, y_beac);
+        output.push((loc_sens, loc_beac))
+    }
+    output
+}
+
+/// Solves both parts of AOC 2022 Day 15 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day15_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 15 Part 1 // Determines the number of locations in the row where y=2000000
+/// which cannot contain a beacon.
+pub fn solve_part1(input: &[(Point2D, Point2D)]) -> usize {
+    let mut target_row_locs: HashSet<Point2D> = HashSet::new();
+    let beacons_in_target_row = input
+        .iter()
+        .map(|x| x.1)
+        .filter(|x| x.y() == PART1_TARGET_ROW)
+        .collect::<HashSet<Point2D>>();
+    for (loc_sens, loc_beac) in input {
+        let output = find_beacon_exclusion_locations_in_row(loc_sens, loc_beac, PART1_TARGET_ROW);
+        target_row_locs.extend(output);
+    }
+    for beacon in beacons_in_target_row {
+        if target_row_locs.contains(&beacon) {
+            target_row_locs.remove(&beacon);
+        }
+    }
+    target_row_locs.len()
+}
+
+/// Solves AOC 2022 Day 15 Part 2 // Determines the tuning frequency of the distress beacon.
+pub fn solve_part2(input: &[(Point2D, Point2D)]) -> i64 {
+    for y in 0..=PART2_ROW_LIMIT {
+        let mut ranges: Vec<RangeInclusive<i64>> = vec![];
+        // Find the exclusion zones in the current row from the sensors
+        for (loc_sens, loc_beac) in input {
+            let mdist = loc_sens.calculate_manhattan_distance(loc_beac) as i64;
+            let delta_y = (loc_sens.y() - y).abs();
+            if delta_y > mdist {
+                continue;
+            }
+            let min_x = loc_sens.x() - mdist + delta_y;
+            let max_x = loc_sens.x() + mdist - delta_y;
+            // Skip if the x value is outside the search area
+            if min_x > PART2_ROW_LIMIT || max_x < 0 {
+                continue;
+            }
+            ranges.push(min_x..=max_x);
+        }
+        // Sort the ranges based on their start value
+        ranges.sort_by(|a, b| a.start().cmp(b.start()));
+        // Compare the ranges to find the gap where the distress beacon is located
+        let mut left = 0;
+        let mut right = 1;
+        loop {
+            if right >= ranges.len() {
+                break;
+            }
+            // Check if the x location of the distress beacon has been found
+            if ranges[right].start() - ranges[left].end() == 2 {
+                return (ranges[left].end() + 1) * 4000000 + y;
+            }
+            if ranges[right].end() > ranges[left].end() {
+                left = right;
+                right = left + 1;
+            } else {
+                right += 1;
+            }
+        }
+    }
+    panic!("Day 15 Part 2 - should not get here!");
+}
+
+/// Finds the locations in the specified row that could not contain a beacon.
+fn find_beacon_exclusion_locations_in_row(
+    loc_sens: &Point2D,
+    loc_beac: &Point2D,
+    target_row: i64,
+) -> HashSet<Point2D> {
+    let m_dist = loc_sens.calculate_manhattan_distance(loc_beac) as i64;
+    let delta_y = (loc_sens.y() - target_row).abs();
+    let mut output: HashSet<Point2D> = HashSet::new();
+    for x in (loc_sens.x() - m_dist + delta_y)..=(loc_sens.x() + m_dist - delta_y) {
+        output.insert(Point2D::new(x, target_row));
+    }
+    output
+}
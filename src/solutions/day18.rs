@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use crate::utils::cartography::{exterior_surface_area, surface_area, Point3D};
+use crate::utils::parsing::input_lines;
+
+/// Parsed form of the AOC 2022 Day 18 input used by the solver functions.
+pub type ProblemInput = HashSet<Point3D>;
+
+/// Parses the raw AOC 2022 Day 18 input in the format required by the solver functions.
+/// Returned value is vector of Point3D structs using the co-ordinates listed in the input file.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    let lines = input_lines(raw_input, true);
+    let mut output: HashSet<Point3D> = HashSet::new();
+    for line in lines {
+        let coords = line
+            .split(',')
+            .map(|elem| elem.parse::<i64>().unwrap())
+            .collect::<Vec<i64>>();
+        output.insert(Point3D::new(coords[0], coords[1], coords[2]));
+    }
+    output
+}
+
+/// Solves both parts of AOC 2022 Day 18 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day18_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 18 Part 1 // Determines the surface area of the scanned lava droplet.
+pub fn solve_part1(observed_cubes: &HashSet<Point3D>) -> u64 {
+    surface_area(observed_cubes)
+}
+
+/// Solves AOC 2022 Day 18 Part 2 // Determines the external surface area of the scanned lava
+/// droplet.
+pub fn solve_part2(observed_cubes: &HashSet<Point3D>) -> u64 {
+    exterior_surface_area(observed_cubes)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::utils::runner::load_test_input;
+
+    use super::*;
+
+    /// Tests the Day 18 Part 1 solver method against example input 001.
+    #[test]
+    fn test_day18_part1_t001() {
+        let input = parse_input(&load_test_input(18, "t001"));
+        let solution = solve_part1(&input);
+        assert_eq!(64, solution);
+    }
+
+    /// Tests the Day 18 Part 1 solver method against example input 001.
+    #[test]
+    fn test_day18_part2_t001() {
+        let input = parse_input(&load_test_input(18, "t001"));
+        let solution = solve_part2(&input);
+        assert_eq!(58, solution);
+    }
+}
@@ -0,0 +1,1216 @@
+use std::collections::{BinaryHeap, HashSet};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use regex::Regex;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use crate::utils::math::Capped;
+use crate::utils::parsing::AocError;
+
+/// Parsed form of the AOC 2022 Day 19 input used by the solver functions.
+pub type ProblemInput = Vec<Blueprint>;
+
+const PART1_MINUTES_ALLOWED: u64 = 24;
+const PART2_MINUTES_ALLOWED: u64 = 32;
+
+/// Callback used to report progress through the blueprints being simulated. Arguments are the
+/// number of blueprints completed, the total number of blueprints and the time elapsed so far.
+pub type ProgressCallback<'a> = &'a dyn Fn(usize, usize, Duration);
+
+/// Represents the different kinds of robot.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, EnumIter)]
+pub enum RobotType {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+impl RobotType {
+    /// Gets the type of resource collected by the robot.
+    pub fn produces(&self) -> ResourceType {
+        match self {
+            RobotType::Ore => ResourceType::Ore,
+            RobotType::Clay => ResourceType::Clay,
+            RobotType::Obsidian => ResourceType::Obsidian,
+            RobotType::Geode => ResourceType::Geode,
+        }
+    }
+}
+
+/// Represents the different kinds of resource.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, EnumIter)]
+pub enum ResourceType {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+/// Used to track totals associated with the different types of resources.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ResourceBag {
+    ore: u64,
+    clay: u64,
+    obsidian: u64,
+    geode: u64,
+}
+
+impl ResourceBag {
+    pub fn new(ore: u64, clay: u64, obsidian: u64, geode: u64) -> Self {
+        Self {
+            ore,
+            clay,
+            obsidian,
+            geode,
+        }
+    }
+
+    /// Creates a new resource bag from the given counts, ordered as ore, clay, obsidian and geode.
+    pub fn from_counts(counts: [u64; 4]) -> Self {
+        let [ore, clay, obsidian, geode] = counts;
+        Self::new(ore, clay, obsidian, geode)
+    }
+
+    /// Checks if the other resource bag has less than or equal to the resources of the current
+    /// resource bag.
+    pub fn fits_within(&self, other: &ResourceBag) -> bool {
+        self.ore >= other.ore
+            && self.clay >= other.clay
+            && self.obsidian >= other.obsidian
+            && self.geode >= other.geode
+    }
+
+    /// Gets the amount of the given resource type held in the resource bag.
+    pub fn amount_of(&self, resource: ResourceType) -> u64 {
+        match resource {
+            ResourceType::Ore => self.ore,
+            ResourceType::Clay => self.clay,
+            ResourceType::Obsidian => self.obsidian,
+            ResourceType::Geode => self.geode,
+        }
+    }
+
+    /// Adds the given amount to the amount of the given resource type held in the resource bag.
+    pub fn add_amount(&mut self, resource: ResourceType, amount: u64) {
+        match resource {
+            ResourceType::Ore => self.ore += amount,
+            ResourceType::Clay => self.clay += amount,
+            ResourceType::Obsidian => self.obsidian += amount,
+            ResourceType::Geode => self.geode += amount,
+        }
+    }
+
+    /// Adds one to the amount of the given resource type held in the resource bag.
+    pub fn add_one(&mut self, resource: ResourceType) {
+        self.add_amount(resource, 1);
+    }
+
+    /// Returns the result of subtracting the other resource bag from the current resource bag, with
+    /// each field saturating at zero rather than wrapping around.
+    pub fn saturating_sub(&self, other: &ResourceBag) -> ResourceBag {
+        ResourceBag {
+            ore: self.ore.saturating_sub(other.ore),
+            clay: self.clay.saturating_sub(other.clay),
+            obsidian: self.obsidian.saturating_sub(other.obsidian),
+            geode: self.geode.saturating_sub(other.geode),
+        }
+    }
+
+    /// Returns the resources remaining after spending the cost given by the other resource bag.
+    /// Debug builds assert that the cost can be afforded, with release builds saturating at zero.
+    pub fn spend(&self, cost: &ResourceBag) -> ResourceBag {
+        debug_assert!(
+            self.fits_within(cost),
+            "Day 19 - cannot afford resource cost!"
+        );
+        self.saturating_sub(cost)
+    }
+
+    /// Returns a resource bag with all fields initialised to zero.
+    pub fn blank() -> ResourceBag {
+        ResourceBag {
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+        }
+    }
+}
+
+impl fmt::Display for ResourceBag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ore={} clay={} obsidian={} geode={}",
+            self.ore, self.clay, self.obsidian, self.geode
+        )
+    }
+}
+
+/// Represents a blueprint with robots having different costs
+pub struct Blueprint {
+    id: u64,
+    ore_robot: ResourceBag,
+    clay_robot: ResourceBag,
+    obsidian_robot: ResourceBag,
+    geode_robot: ResourceBag,
+}
+
+impl Blueprint {
+    fn new(
+        id: u64,
+        ore_robot: ResourceBag,
+        clay_robot: ResourceBag,
+        obsidian_robot: ResourceBag,
+        geode_robot: ResourceBag,
+    ) -> Self {
+        Self {
+            id,
+            ore_robot,
+            clay_robot,
+            obsidian_robot,
+            geode_robot,
+        }
+    }
+
+    /// Gets the resources needed to build a robot of the given type.
+    pub fn cost_of(&self, robot: RobotType) -> ResourceBag {
+        match robot {
+            RobotType::Ore => self.ore_robot,
+            RobotType::Clay => self.clay_robot,
+            RobotType::Obsidian => self.obsidian_robot,
+            RobotType::Geode => self.geode_robot,
+        }
+    }
+}
+
+/// Builder used to create a blueprint from the seven numbers given in its description: the
+/// blueprint ID and the costs of each type of robot.
+pub struct BlueprintBuilder {
+    id: u64,
+    ore_robot: ResourceBag,
+    clay_robot: ResourceBag,
+    obsidian_robot: ResourceBag,
+    geode_robot: ResourceBag,
+}
+
+impl BlueprintBuilder {
+    /// Creates a new builder for the blueprint with the given ID, with all robots initially free.
+    pub fn new(id: u64) -> Self {
+        Self {
+            id,
+            ore_robot: ResourceBag::blank(),
+            clay_robot: ResourceBag::blank(),
+            obsidian_robot: ResourceBag::blank(),
+            geode_robot: ResourceBag::blank(),
+        }
+    }
+
+    /// Sets the cost of an ore robot.
+    pub fn ore_robot(mut self, ore: u64) -> Self {
+        self.ore_robot = ResourceBag::from_counts([ore, 0, 0, 0]);
+        self
+    }
+
+    /// Sets the cost of a clay robot.
+    pub fn clay_robot(mut self, ore: u64) -> Self {
+        self.clay_robot = ResourceBag::from_counts([ore, 0, 0, 0]);
+        self
+    }
+
+    /// Sets the cost of an obsidian robot.
+    pub fn obsidian_robot(mut self, ore: u64, clay: u64) -> Self {
+        self.obsidian_robot = ResourceBag::from_counts([ore, clay, 0, 0]);
+        self
+    }
+
+    /// Sets the cost of a geode robot.
+    pub fn geode_robot(mut self, ore: u64, obsidian: u64) -> Self {
+        self.geode_robot = ResourceBag::from_counts([ore, 0, obsidian, 0]);
+        self
+    }
+
+    /// Creates the blueprint with the robot costs set in the builder.
+    pub fn build(self) -> Blueprint {
+        Blueprint::new(
+            self.id,
+            self.ore_robot,
+            self.clay_robot,
+            self.obsidian_robot,
+            self.geode_robot,
+        )
+    }
+}
+
+/// Parses the raw Day 19 input into the vector of blueprints. Each blueprint can be given on a
+/// single line or have its sentences spread across multiple lines, with a new blueprint starting on
+/// each line beginning with "Blueprint". Parse errors report the line on which the blueprint starts.
+pub fn parse_input(raw_input: &str) -> Result<Vec<Blueprint>, AocError> {
+    let regex_blueprint = Regex::new(concat!(
+        r#"^Blueprint (\d+): Each ore robot costs (\d+) ore. Each clay robot costs (\d+) ore. "#,
+        r#"Each obsidian robot costs (\d+) ore and (\d+) clay. "#,
+        r#"Each geode robot costs (\d+) ore and (\d+) obsidian.$"#,
+    ))
+    .unwrap();
+    let mut blueprints: Vec<Blueprint> = vec![];
+    for (line, text) in join_blueprint_lines(raw_input) {
+        let parse_error = |reason: &str| AocError::Parse {
+            line,
+            reason: reason.to_string(),
+        };
+        let caps = regex_blueprint
+            .captures(&text)
+            .ok_or_else(|| parse_error("line does not match the blueprint format"))?;
+        // Extract parameters from input line
+        let mut values: Vec<u64> = vec![];
+        for cap in caps.iter().skip(1) {
+            let value = cap.unwrap().as_str().parse::<u64>();
+            values.push(value.map_err(|_| parse_error("number is too large"))?);
+        }
+        // Create and record the blueprint
+        let bp = BlueprintBuilder::new(values[0])
+            .ore_robot(values[1])
+            .clay_robot(values[2])
+            .obsidian_robot(values[3], values[4])
+            .geode_robot(values[5], values[6])
+            .build();
+        blueprints.push(bp);
+    }
+    Ok(blueprints)
+}
+
+/// Solves both parts of AOC 2022 Day 19 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day19_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input).unwrap_or_else(|err| panic!("Day 19 - {}", err));
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Joins the lines of the raw input into the text of each blueprint, with continuation lines joined
+/// by a single space. Returned value is the line number (starting at 1) on which each blueprint
+/// starts, along with the text of the blueprint.
+fn join_blueprint_lines(raw_input: &str) -> Vec<(usize, String)> {
+    let mut joined: Vec<(usize, String)> = vec![];
+    for (i, line) in raw_input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match joined.last_mut() {
+            Some((_, text)) if !line.starts_with("Blueprint") => {
+                text.push(' ');
+                text.push_str(line);
+            }
+            _ => joined.push((i + 1, line.to_string())),
+        }
+    }
+    joined
+}
+
+/// Solves AOC 2022 Day 19 Part 1 // Calculates the sum of the quality levels of the blueprints
+/// with 24 minutes allowed for each to run.
+pub fn solve_part1(blueprints: &[Blueprint]) -> u64 {
+    solve_part1_with_progress(blueprints, None)
+}
+
+/// Solves AOC 2022 Day 19 Part 1, invoking the progress callback (if provided) after each blueprint
+/// has been simulated.
+pub fn solve_part1_with_progress(
+    blueprints: &[Blueprint],
+    progress: Option<ProgressCallback>,
+) -> u64 {
+    solve_part1_with_budget(blueprints, progress, None)
+}
+
+/// Solves AOC 2022 Day 19 Part 1, stopping the search of each blueprint early if the wall-clock time
+/// budget (if provided) elapses. The result is no greater than the true solution if any search is
+/// stopped early. The progress callback (if provided) is invoked after each blueprint.
+pub fn solve_part1_with_budget(
+    blueprints: &[Blueprint],
+    progress: Option<ProgressCallback>,
+    budget: Option<Duration>,
+) -> u64 {
+    let start = Instant::now();
+    let mut total = 0;
+    for (i, bp) in blueprints.iter().enumerate() {
+        let geodes = simulate_blueprint_with_budget(bp, PART1_MINUTES_ALLOWED, budget);
+        log::info!("Day 19 - blueprint {} opens {} geodes", bp.id, geodes);
+        total += geodes * bp.id;
+        if let Some(callback) = progress {
+            callback(i + 1, blueprints.len(), start.elapsed());
+        }
+    }
+    total
+}
+
+/// Solves AOC 2022 Day 19 Part 2 // Calculates the product of the maximum geode numbers from the
+/// first three blueprints with 32 minutes allowed for each to run.
+pub fn solve_part2(blueprints: &[Blueprint]) -> u64 {
+    solve_part2_with_progress(blueprints, None)
+}
+
+/// Solves AOC 2022 Day 19 Part 2, invoking the progress callback (if provided) after each blueprint
+/// has been simulated.
+pub fn solve_part2_with_progress(
+    blueprints: &[Blueprint],
+    progress: Option<ProgressCallback>,
+) -> u64 {
+    solve_part2_with_budget(blueprints, progress, None)
+}
+
+/// Solves AOC 2022 Day 19 Part 2, stopping the search of each blueprint early if the wall-clock time
+/// budget (if provided) elapses. The result is no greater than the true solution if any search is
+/// stopped early. The progress callback (if provided) is invoked after each blueprint.
+pub fn solve_part2_with_budget(
+    blueprints: &[Blueprint],
+    progress: Option<ProgressCallback>,
+    budget: Option<Duration>,
+) -> u64 {
+    let start = Instant::now();
+    let blueprints = take_first_n(blueprints, 3);
+    let mut values: Vec<u64> = vec![];
+    for (i, bp) in blueprints.iter().enumerate() {
+        let geodes = simulate_blueprint_with_budget(bp, PART2_MINUTES_ALLOWED, budget);
+        log::info!("Day 19 - blueprint {} opens {} geodes", bp.id, geodes);
+        values.push(geodes);
+        if let Some(callback) = progress {
+            callback(i + 1, blueprints.len(), start.elapsed());
+        }
+    }
+    values.iter().product()
+}
+
+/// Gets the first n blueprints, or all of the blueprints if there are fewer than n.
+fn take_first_n(blueprints: &[Blueprint], n: usize) -> &[Blueprint] {
+    &blueprints[..blueprints.len().min(n)]
+}
+
+/// Determines the maximum number of geodes that the given blueprint could produce in the allowed
+/// time (measured in minutes).
+pub fn simulate_blueprint(blueprint: &Blueprint, time_allowed: u64) -> u64 {
+    simulate_blueprint_with_cache(blueprint, time_allowed, true).0
+}
+
+/// Determines the maximum number of geodes that the given blueprint could produce in the allowed
+/// time (measured in minutes), stopping early if the wall-clock time budget (if provided) elapses.
+/// If the search is stopped early, the best number of geodes found so far is returned.
+pub fn simulate_blueprint_with_budget(
+    blueprint: &Blueprint,
+    time_allowed: u64,
+    budget: Option<Duration>,
+) -> u64 {
+    let mut context = SearchContext::new(blueprint, time_allowed, true);
+    context.deadline = budget.map(|budget| Instant::now() + budget);
+    search_from_start(blueprint, &mut context);
+    *context.geode_totals.iter().max().unwrap()
+}
+
+/// Determines the maximum number of geodes that the given blueprint could produce in the allowed
+/// time (measured in minutes), along with the timeline of robot builds that achieves it. Each entry
+/// in the timeline is the minute in which construction of the robot started and the robot type.
+pub fn simulate_blueprint_with_timeline(
+    blueprint: &Blueprint,
+    time_allowed: u64,
+) -> (u64, Vec<(u64, RobotType)>) {
+    let context = run_search(blueprint, time_allowed, true);
+    context.best
+}
+
+/// Determines the maximum number of geodes that the given blueprint could produce in the allowed
+/// time (measured in minutes), optionally skipping search states that have already been visited.
+/// Returned value is the maximum number of geodes and the number of search nodes expanded.
+fn simulate_blueprint_with_cache(
+    blueprint: &Blueprint,
+    time_allowed: u64,
+    use_cache: bool,
+) -> (u64, u64) {
+    let context = run_search(blueprint, time_allowed, use_cache);
+    (*context.geode_totals.iter().max().unwrap(), context.nodes)
+}
+
+/// Runs the depth-first search for the given blueprint, returning the final search context.
+fn run_search(blueprint: &Blueprint, time_allowed: u64, use_cache: bool) -> SearchContext {
+    let mut context = SearchContext::new(blueprint, time_allowed, use_cache);
+    search_from_start(blueprint, &mut context);
+    context
+}
+
+/// Runs the depth-first search for the given blueprint from the starting resources and robots.
+fn search_from_start(blueprint: &Blueprint, context: &mut SearchContext) {
+    let resource_blank = ResourceBag::blank();
+    let robot_start = ResourceBag::new(1, 0, 0, 0);
+    let time_allowed = context.time_allowed;
+    simulate_blueprint_iterative(
+        blueprint,
+        context,
+        resource_blank,
+        robot_start,
+        time_allowed,
+    );
+}
+
+/// Key used to identify equivalent search states: the capped resources, the robots and the time
+/// remaining.
+type StateKey = (ResourceBag, ResourceBag, u64);
+
+/// Holds the state shared across the depth-first search of a blueprint.
+struct SearchContext {
+    time_allowed: u64,
+    geode_totals: HashSet<u64>,
+    /// Robots built along the current search path, as (minute, robot type) pairs.
+    path: Vec<(u64, RobotType)>,
+    /// Maximum number of geodes found so far and the robots built to achieve it.
+    best: (u64, Vec<(u64, RobotType)>),
+    earliest_geode_robot_time: u64,
+    robot_limits: ResourceBag,
+    visited: Option<HashSet<StateKey>>,
+    nodes: u64,
+    /// Time at which the search is stopped early, if a time budget has been set.
+    deadline: Option<Instant>,
+}
+
+impl SearchContext {
+    pub fn new(blueprint: &Blueprint, time_allowed: u64, use_cache: bool) -> Self {
+        Self {
+            time_allowed,
+            geode_totals: HashSet::from([0]),
+            path: vec![],
+            best: (0, vec![]),
+            earliest_geode_robot_time: 0,
+            robot_limits: robot_limits(blueprint),
+            visited: if use_cache {
+                Some(HashSet::new())
+            } else {
+                None
+            },
+            nodes: 0,
+            deadline: None,
+        }
+    }
+
+    /// Checks if the deadline for the search (if set) has passed.
+    pub fn out_of_time(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Records the given state as visited. Returns false if the cache is enabled and an
+    /// equivalent state has already been visited with the same time remaining.
+    pub fn visit(
+        &mut self,
+        resource_total: &ResourceBag,
+        robot_total: &ResourceBag,
+        time_remaining: u64,
+    ) -> bool {
+        self.nodes += 1;
+        let limits = self.robot_limits;
+        match self.visited.as_mut() {
+            Some(visited) => {
+                // Resources beyond what could be spent in the remaining time make no difference
+                let cap = |resource: ResourceType| {
+                    let limit = limits.amount_of(resource).saturating_mul(time_remaining);
+                    Capped::new(resource_total.amount_of(resource), limit).value()
+                };
+                let capped = ResourceBag::new(
+                    cap(ResourceType::Ore),
+                    cap(ResourceType::Clay),
+                    cap(ResourceType::Obsidian),
+                    resource_total.geode,
+                );
+                visited.insert((capped, *robot_total, time_remaining))
+            }
+            None => true,
+        }
+    }
+}
+
+/// Determines the maximum number of geodes that the given blueprint could produce in the allowed
+/// time (measured in minutes), using a best-first search as an alternative to the depth-first
+/// search used by simulate_blueprint(). States are expanded in order of their optimistic upper
+/// bound on the geodes that could be opened, and the search stops once no remaining state could
+/// beat the best total found so far.
+pub fn simulate_blueprint_best_first(blueprint: &Blueprint, time_allowed: u64) -> u64 {
+    let robot_limits = robot_limits(blueprint);
+    let robot_start = ResourceBag::new(1, 0, 0, 0);
+    let mut best = 0;
+    let mut queue: BinaryHeap<(u64, u64, ResourceBag, ResourceBag)> = BinaryHeap::new();
+    queue.push((
+        geode_upper_bound(&ResourceBag::blank(), &robot_start, time_allowed),
+        time_allowed,
+        ResourceBag::blank(),
+        robot_start,
+    ));
+    while let Some((bound, time_remaining, resource_total, robot_total)) = queue.pop() {
+        // All remaining states have an upper bound no better than the current state
+        if bound <= best {
+            break;
+        }
+        // Geodes opened if no more robots are built
+        best = best.max(resource_total.geode + robot_total.geode * time_remaining);
+        // Jump ahead to the construction of each type of robot that is worth building next
+        for robot_type in RobotType::iter() {
+            if robot_total.amount_of(robot_type.produces())
+                >= robot_limits.amount_of(robot_type.produces())
+            {
+                continue;
+            }
+            let cost = blueprint.cost_of(robot_type);
+            let wait = match minutes_until_affordable(&resource_total, &robot_total, &cost) {
+                Some(wait) if wait + 1 < time_remaining => wait,
+                _ => continue,
+            };
+            let mut resource_total = resource_total;
+            for resource in ResourceType::iter() {
+                resource_total.add_amount(resource, robot_total.amount_of(resource) * (wait + 1));
+            }
+            let resource_total = resource_total.spend(&cost);
+            let mut robot_total = robot_total;
+            robot_total.add_one(robot_type.produces());
+            let time_remaining = time_remaining - wait - 1;
+            let bound = geode_upper_bound(&resource_total, &robot_total, time_remaining);
+            if bound > best {
+                queue.push((bound, time_remaining, resource_total, robot_total));
+            }
+        }
+    }
+    best
+}
+
+/// Gets the maximum number of each type of robot worth building for the given blueprint. No more
+/// robots are needed for a resource than can be spent in a single minute, with no limit applied to
+/// geode robots.
+fn robot_limits(blueprint: &Blueprint) -> ResourceBag {
+    let mut limits = ResourceBag::new(0, 0, 0, u64::MAX);
+    for robot_type in RobotType::iter() {
+        let cost = blueprint.cost_of(robot_type);
+        limits.ore = limits.ore.max(cost.ore);
+        limits.clay = limits.clay.max(cost.clay);
+        limits.obsidian = limits.obsidian.max(cost.obsidian);
+    }
+    limits
+}
+
+/// Calculates the number of minutes until the given cost can be afforded with the current
+/// resources and robots. Returns None if the robots needed to collect the resources are missing.
+fn minutes_until_affordable(
+    resource_total: &ResourceBag,
+    robot_total: &ResourceBag,
+    cost: &ResourceBag,
+) -> Option<u64> {
+    let mut wait = 0;
+    for resource in ResourceType::iter() {
+        let needed = cost
+            .amount_of(resource)
+            .saturating_sub(resource_total.amount_of(resource));
+        if needed == 0 {
+            continue;
+        }
+        let rate = robot_total.amount_of(resource);
+        if rate == 0 {
+            return None;
+        }
+        wait = wait.max(needed.div_ceil(rate));
+    }
+    Some(wait)
+}
+
+/// Calculates an optimistic upper bound on the geodes that could be opened in the remaining time,
+/// assuming a new geode robot could be built every minute.
+fn geode_upper_bound(resource_total: &ResourceBag, robot_total: &ResourceBag, time: u64) -> u64 {
+    resource_total.geode + robot_total.geode * time + time * time.saturating_sub(1) / 2
+}
+
+/// Frame held on the explicit stack used by simulate_blueprint_iterative(). Each frame records the
+/// length of the build path at the point it was pushed, so the path can be unwound when the frame
+/// is popped.
+enum SearchFrame {
+    /// Search state to be entered.
+    State {
+        resource_total: ResourceBag,
+        robot_total: ResourceBag,
+        time_remaining: u64,
+        path_len: usize,
+    },
+    /// Robot to be built (if any) from the given search state.
+    Build {
+        resource_total: ResourceBag,
+        robot_total: ResourceBag,
+        time_remaining: u64,
+        robot: Option<RobotType>,
+        path_len: usize,
+    },
+}
+
+/// Depth-first search of the states reachable by the given blueprint, using an explicit stack of
+/// frames so that long time horizons cannot overflow the call stack.
+fn simulate_blueprint_iterative(
+    blueprint: &Blueprint,
+    context: &mut SearchContext,
+    resource_total: ResourceBag,
+    robot_total: ResourceBag,
+    time_remaining: u64,
+) {
+    let mut stack: Vec<SearchFrame> = vec![SearchFrame::State {
+        resource_total,
+        robot_total,
+        time_remaining,
+        path_len: context.path.len(),
+    }];
+    let mut frames_popped: u64 = 0;
+    while let Some(frame) = stack.pop() {
+        // Only check the clock periodically, since the search handles a large number of frames
+        frames_popped += 1;
+        if frames_popped.is_multiple_of(1024) && context.out_of_time() {
+            break;
+        }
+        match frame {
+            SearchFrame::State {
+                resource_total,
+                robot_total,
+                time_remaining,
+                path_len,
+            } => {
+                context.path.truncate(path_len);
+                let to_build = match enter_state(
+                    blueprint,
+                    context,
+                    &resource_total,
+                    &robot_total,
+                    time_remaining,
+                ) {
+                    Some(to_build) => to_build,
+                    None => continue,
+                };
+                // Push in reverse so the robots are tried in the order given by enter_state()
+                for robot in to_build.into_iter().rev() {
+                    stack.push(SearchFrame::Build {
+                        resource_total,
+                        robot_total,
+                        time_remaining,
+                        robot,
+                        path_len,
+                    });
+                }
+            }
+            SearchFrame::Build {
+                resource_total,
+                robot_total,
+                time_remaining,
+                robot,
+                path_len,
+            } => {
+                context.path.truncate(path_len);
+                if let Some((resource_total, robot_total)) = apply_build(
+                    blueprint,
+                    context,
+                    resource_total,
+                    robot_total,
+                    time_remaining,
+                    robot,
+                ) {
+                    stack.push(SearchFrame::State {
+                        resource_total,
+                        robot_total,
+                        time_remaining: time_remaining - 1,
+                        path_len: context.path.len(),
+                    });
+                }
+            }
+        }
+    }
+    context.path.clear();
+}
+
+/// Enters the given search state, recording the geode total if no time remains. Returns the robots
+/// that could be built next (with None meaning no robot is built), or None if the search ends or is
+/// pruned at this state.
+fn enter_state(
+    blueprint: &Blueprint,
+    context: &mut SearchContext,
+    resource_total: &ResourceBag,
+    robot_total: &ResourceBag,
+    time_remaining: u64,
+) -> Option<Vec<Option<RobotType>>> {
+    if time_remaining == 0 {
+        if context.geode_totals.insert(resource_total.geode) {
+            log::debug!(
+                "Day 19 - new geode total {} for blueprint {}",
+                resource_total.geode,
+                blueprint.id
+            );
+        }
+        if resource_total.geode > context.best.0 {
+            context.best = (resource_total.geode, context.path.clone());
+        }
+        return None;
+    }
+    // prune - equivalent state already explored
+    if !context.visit(resource_total, robot_total, time_remaining) {
+        return None;
+    }
+    // prune
+    if robot_total.obsidian > blueprint.geode_robot.obsidian {
+        return None;
+    }
+    // prune
+    if time_remaining + 1 < context.earliest_geode_robot_time && robot_total.geode == 0 {
+        return None;
+    }
+    // Try to build robots
+    let mut to_build: Vec<Option<RobotType>> = vec![None];
+    for robot_type in RobotType::iter() {
+        if resource_total.fits_within(&blueprint.cost_of(robot_type)) {
+            if robot_type == RobotType::Geode {
+                to_build = vec![Some(RobotType::Geode)];
+                break;
+            } else {
+                to_build.push(Some(robot_type));
+            }
+        }
+    }
+    Some(to_build)
+}
+
+/// Advances the search state by one minute, building the given robot (if any) and adding it to the
+/// build path. Returns the resulting resources and robots, or None if the build is pruned.
+fn apply_build(
+    blueprint: &Blueprint,
+    context: &mut SearchContext,
+    resource_total: ResourceBag,
+    robot_total: ResourceBag,
+    time_remaining: u64,
+    robot: Option<RobotType>,
+) -> Option<(ResourceBag, ResourceBag)> {
+    let mut resource_total = resource_total;
+    // prune - dead end if there no geodes with two or less minutes remaining and no geode bots
+    if time_remaining <= 2 && robot_total.geode == 0 && robot != Some(RobotType::Geode) {
+        return None;
+    }
+    match robot {
+        Some(RobotType::Geode) if time_remaining > context.earliest_geode_robot_time => {
+            context.earliest_geode_robot_time = time_remaining;
+        }
+        Some(RobotType::Geode) => (),
+        // prune - don't build a non-geode robot with two or less minutes remaining
+        Some(_) if time_remaining <= 2 => return None,
+        _ => (),
+    }
+    // Spend the resources needed to build the robot
+    if let Some(robot_type) = robot {
+        resource_total = resource_total.spend(&blueprint.cost_of(robot_type));
+    }
+    // Collect resources
+    for resource in ResourceType::iter() {
+        resource_total.add_amount(resource, robot_total.amount_of(resource));
+    }
+    // Check for robot construction
+    let mut robot_total = robot_total;
+    if let Some(robot_type) = robot {
+        robot_total.add_one(robot_type.produces());
+        let minute = context.time_allowed - time_remaining + 1;
+        context.path.push((minute, robot_type));
+    }
+    Some((resource_total, robot_total))
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+    use std::sync::{Mutex, Once};
+
+    use log::{Level, Log, Metadata, Record};
+
+    use crate::utils::runner::load_test_input;
+
+    use super::*;
+
+    /// Tests the Day 19 Part 1 solver method against example input 001.
+    #[test]
+    fn test_day19_part1_t001() {
+        let input = parse_input(&load_test_input(19, "t001")).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(33, solution);
+    }
+
+    /// Tests the maximum number of geodes opened by each blueprint from example input 001 within
+    /// the Part 1 time limit.
+    #[test]
+    fn test_day19_simulate_blueprint_t001() {
+        let input = parse_input(&load_test_input(19, "t001")).unwrap();
+        assert_eq!(2, input.len());
+        assert_eq!(9, simulate_blueprint(&input[0], PART1_MINUTES_ALLOWED));
+        assert_eq!(12, simulate_blueprint(&input[1], PART1_MINUTES_ALLOWED));
+    }
+
+    /// Tests that the best-first search gives the same maximum number of geodes for each blueprint
+    /// from example input 001 as the depth-first search.
+    #[test]
+    fn test_day19_simulate_blueprint_best_first_t001() {
+        let input = parse_input(&load_test_input(19, "t001")).unwrap();
+        for bp in input.iter() {
+            assert_eq!(
+                simulate_blueprint(bp, PART1_MINUTES_ALLOWED),
+                simulate_blueprint_best_first(bp, PART1_MINUTES_ALLOWED)
+            );
+        }
+        assert_eq!(
+            56,
+            simulate_blueprint_best_first(&input[0], PART2_MINUTES_ALLOWED)
+        );
+        assert_eq!(
+            62,
+            simulate_blueprint_best_first(&input[1], PART2_MINUTES_ALLOWED)
+        );
+    }
+
+    /// Tests that skipping previously visited states gives the same maximum number of geodes for
+    /// each blueprint from example input 001, while expanding fewer search nodes.
+    #[test]
+    fn test_day19_simulate_blueprint_cache_t001() {
+        let input = parse_input(&load_test_input(19, "t001")).unwrap();
+        for bp in input.iter() {
+            let (geodes_cached, nodes_cached) =
+                simulate_blueprint_with_cache(bp, PART1_MINUTES_ALLOWED, true);
+            let (geodes_uncached, nodes_uncached) =
+                simulate_blueprint_with_cache(bp, PART1_MINUTES_ALLOWED, false);
+            assert_eq!(geodes_uncached, geodes_cached);
+            assert!(nodes_cached < nodes_uncached);
+        }
+    }
+
+    /// Replays the given timeline of robot builds for the blueprint, returning the number of geodes
+    /// opened in the allowed time. Panics if a robot is built before it can be afforded.
+    fn replay_timeline(
+        blueprint: &Blueprint,
+        time_allowed: u64,
+        timeline: &[(u64, RobotType)],
+    ) -> u64 {
+        let mut resource_total = ResourceBag::blank();
+        let mut robot_total = ResourceBag::new(1, 0, 0, 0);
+        for minute in 1..=time_allowed {
+            let build = timeline.iter().find(|(m, _)| *m == minute).map(|(_, r)| *r);
+            if let Some(robot_type) = build {
+                let cost = blueprint.cost_of(robot_type);
+                assert!(resource_total.fits_within(&cost));
+                resource_total = resource_total.spend(&cost);
+            }
+            for resource in ResourceType::iter() {
+                resource_total.add_amount(resource, robot_total.amount_of(resource));
+            }
+            if let Some(robot_type) = build {
+                robot_total.add_one(robot_type.produces());
+            }
+        }
+        resource_total.geode
+    }
+
+    /// Tests that replaying the build timeline reported for each blueprint from example input 001
+    /// opens the claimed number of geodes.
+    #[test]
+    fn test_day19_simulate_blueprint_with_timeline_t001() {
+        let input = parse_input(&load_test_input(19, "t001")).unwrap();
+        for (bp, expected) in input.iter().zip([9, 12]) {
+            let (geodes, timeline) = simulate_blueprint_with_timeline(bp, PART1_MINUTES_ALLOWED);
+            assert_eq!(expected, geodes);
+            assert!(timeline.windows(2).all(|w| w[0].0 < w[1].0));
+            assert_eq!(
+                geodes,
+                replay_timeline(bp, PART1_MINUTES_ALLOWED, &timeline)
+            );
+        }
+    }
+
+    /// Recursive version of simulate_blueprint_iterative(), used as a reference to check that the
+    /// iterative search explores the same states.
+    fn simulate_blueprint_recursive(
+        blueprint: &Blueprint,
+        context: &mut SearchContext,
+        resource_total: ResourceBag,
+        robot_total: ResourceBag,
+        time_remaining: u64,
+    ) {
+        let to_build = match enter_state(
+            blueprint,
+            context,
+            &resource_total,
+            &robot_total,
+            time_remaining,
+        ) {
+            Some(to_build) => to_build,
+            None => return,
+        };
+        for robot in to_build {
+            let (resource_total, robot_total) = match apply_build(
+                blueprint,
+                context,
+                resource_total,
+                robot_total,
+                time_remaining,
+                robot,
+            ) {
+                Some(next_state) => next_state,
+                None => continue,
+            };
+            // Go to the next step
+            simulate_blueprint_recursive(
+                blueprint,
+                context,
+                resource_total,
+                robot_total,
+                time_remaining - 1,
+            );
+            if robot.is_some() {
+                context.path.pop();
+            }
+        }
+    }
+
+    /// Tests that the iterative and recursive versions of the depth-first search explore the same
+    /// states and find the same best solution for each blueprint from example input 001.
+    #[test]
+    fn test_day19_simulate_blueprint_iterative_matches_recursive_t001() {
+        let input = parse_input(&load_test_input(19, "t001")).unwrap();
+        for bp in input.iter() {
+            let start = ResourceBag::new(1, 0, 0, 0);
+            let mut iterative = SearchContext::new(bp, PART1_MINUTES_ALLOWED, true);
+            let mut recursive = SearchContext::new(bp, PART1_MINUTES_ALLOWED, true);
+            let blank = ResourceBag::blank();
+            simulate_blueprint_iterative(bp, &mut iterative, blank, start, PART1_MINUTES_ALLOWED);
+            simulate_blueprint_recursive(bp, &mut recursive, blank, start, PART1_MINUTES_ALLOWED);
+            assert_eq!(recursive.geode_totals, iterative.geode_totals);
+            assert_eq!(recursive.nodes, iterative.nodes);
+            assert_eq!(recursive.best, iterative.best);
+        }
+    }
+
+    /// Tests that the search stops early when given a tiny time budget, returning a number of
+    /// geodes no greater than the true maximum.
+    #[test]
+    fn test_day19_simulate_blueprint_with_budget_t001() {
+        let input = parse_input(&load_test_input(19, "t001")).unwrap();
+        let budget = Some(Duration::from_millis(1));
+        let geodes = simulate_blueprint_with_budget(&input[1], PART2_MINUTES_ALLOWED, budget);
+        assert!(geodes <= 62);
+        let geodes = simulate_blueprint_with_budget(&input[0], PART1_MINUTES_ALLOWED, None);
+        assert_eq!(9, geodes);
+    }
+
+    /// Tests that the Day 19 solvers give the correct results against example input 001 when no time
+    /// budget is given, and results no greater than the correct results with a tiny time budget.
+    #[test]
+    fn test_day19_solve_with_budget_t001() {
+        let input = parse_input(&load_test_input(19, "t001")).unwrap();
+        assert_eq!(33, solve_part1_with_budget(&input, None, None));
+        let budget = Some(Duration::from_millis(1));
+        assert!(solve_part1_with_budget(&input, None, budget) <= 33);
+        assert!(solve_part2_with_budget(&input, None, budget) <= 3472);
+    }
+
+    /// Tests that the first blueprint from example input 001 can be constructed with the builder,
+    /// and that it opens 9 geodes in 24 minutes.
+    #[test]
+    fn test_day19_blueprint_builder() {
+        let bp = BlueprintBuilder::new(1)
+            .ore_robot(4)
+            .clay_robot(2)
+            .obsidian_robot(3, 14)
+            .geode_robot(2, 7)
+            .build();
+        let input = parse_input(&load_test_input(19, "t001")).unwrap();
+        assert!(bp.id == input[0].id);
+        for robot_type in RobotType::iter() {
+            assert!(bp.cost_of(robot_type) == input[0].cost_of(robot_type));
+        }
+        assert_eq!(9, simulate_blueprint(&bp, PART1_MINUTES_ALLOWED));
+    }
+
+    /// Tests that taking the first three blueprints of an input with only two blueprints gives both
+    /// of the blueprints.
+    #[test]
+    fn test_day19_take_first_n_short_input() {
+        let input = parse_input(&load_test_input(19, "t001")).unwrap();
+        let ids = |bps: &[Blueprint]| bps.iter().map(|bp| bp.id).collect::<Vec<u64>>();
+        assert_eq!(vec![1, 2], ids(take_first_n(&input, 3)));
+        assert_eq!(vec![1], ids(take_first_n(&input, 1)));
+        assert!(take_first_n(&input, 0).is_empty());
+    }
+
+    /// Tests the Day 19 Part 2 solver method against example input 001.
+    #[test]
+    fn test_day19_part2_t001() {
+        let input = parse_input(&load_test_input(19, "t001")).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(3472, solution);
+    }
+
+    /// Tests that subtracting more resources than are available saturates at zero.
+    #[test]
+    fn test_day19_resource_bag_saturating_sub() {
+        let available = ResourceBag::new(3, 5, 0, 2);
+        let cost = ResourceBag::new(4, 2, 1, 2);
+        let remaining = available.saturating_sub(&cost);
+        assert!(remaining == ResourceBag::new(0, 3, 0, 0));
+    }
+
+    /// Tests the display format of the resource bag.
+    #[test]
+    fn test_day19_resource_bag_display() {
+        let bag = ResourceBag::new(4, 0, 12, 1);
+        assert_eq!("ore=4 clay=0 obsidian=12 geode=1", bag.to_string());
+    }
+
+    /// Tests that the resource bag created from an array of counts sets the fields in order.
+    #[test]
+    fn test_day19_resource_bag_from_counts() {
+        let bag = ResourceBag::from_counts([1, 2, 3, 4]);
+        assert!(bag == ResourceBag::new(1, 2, 3, 4));
+    }
+
+    /// Tests that the cost of a geode robot matches the cost parsed from example input 001.
+    #[test]
+    fn test_day19_blueprint_cost_of_geode_robot() {
+        let input = parse_input(&load_test_input(19, "t001")).unwrap();
+        let cost = input[0].cost_of(RobotType::Geode);
+        assert!(cost == input[0].geode_robot);
+        assert!(cost == ResourceBag::new(2, 0, 7, 0));
+    }
+
+    /// Tests that each robot type produces the matching resource type.
+    #[test]
+    fn test_day19_robot_type_produces() {
+        assert_eq!(ResourceType::Ore, RobotType::Ore.produces());
+        assert_eq!(ResourceType::Clay, RobotType::Clay.produces());
+        assert_eq!(ResourceType::Obsidian, RobotType::Obsidian.produces());
+        assert_eq!(ResourceType::Geode, RobotType::Geode.produces());
+    }
+
+    /// Tests the resource bag accessors for individual resource types.
+    #[test]
+    fn test_day19_resource_bag_amount_of_and_add_one() {
+        let mut bag = ResourceBag::new(1, 2, 3, 4);
+        bag.add_one(ResourceType::Obsidian);
+        let amounts = ResourceType::iter()
+            .map(|resource| bag.amount_of(resource))
+            .collect::<Vec<u64>>();
+        assert_eq!(vec![1, 2, 4, 4], amounts);
+    }
+
+    /// Tests that the Day 19 Part 1 solver gives the correct result against example input 001 with
+    /// a progress callback attached, and that the callback is invoked once per blueprint.
+    #[test]
+    fn test_day19_part1_t001_with_progress() {
+        let input = parse_input(&load_test_input(19, "t001")).unwrap();
+        let reports: RefCell<Vec<(usize, usize)>> = RefCell::new(vec![]);
+        let callback = |done: usize, total: usize, _elapsed: Duration| {
+            reports.borrow_mut().push((done, total));
+        };
+        let solution = solve_part1_with_progress(&input, Some(&callback));
+        assert_eq!(33, solution);
+        assert_eq!(vec![(1, 2), (2, 2)], reports.into_inner());
+    }
+
+    /// Tests that malformed lines result in parse errors reporting the line number.
+    #[test]
+    fn test_day19_parse_input_malformed_line() {
+        let raw_input = concat!(
+            "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. ",
+            "Each obsidian robot costs 3 ore and 14 clay. ",
+            "Each geode robot costs 2 ore and 7 obsidian.\n",
+            "\n",
+            "Blueprint 2: Each ore robot costs 2 ore.\n",
+        );
+        match parse_input(raw_input) {
+            Err(AocError::Parse { line, .. }) => assert_eq!(3, line),
+            _ => panic!("Expected a parse error"),
+        }
+        let raw_input = concat!(
+            "Blueprint 1: Each ore robot costs 99999999999999999999 ore. ",
+            "Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. ",
+            "Each geode robot costs 2 ore and 7 obsidian.\n",
+        );
+        match parse_input(raw_input) {
+            Err(AocError::Parse { line, .. }) => assert_eq!(1, line),
+            _ => panic!("Expected a parse error"),
+        }
+    }
+
+    /// Tests parsing a blueprint with its sentences split across multiple lines, in the format used
+    /// by the problem description.
+    #[test]
+    fn test_day19_parse_input_multiline_blueprint() {
+        let raw_input = concat!(
+            "Blueprint 1:\n",
+            "  Each ore robot costs 4 ore.\n",
+            "  Each clay robot costs 2 ore.\n",
+            "  Each obsidian robot costs 3 ore and 14 clay.\n",
+            "  Each geode robot costs 2 ore and 7 obsidian.\n",
+            "\n",
+            "Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore.\n",
+            "Each obsidian robot costs 3 ore and 8 clay.\n",
+            "Each geode robot costs 3 ore and 12 obsidian.\n",
+        );
+        let blueprints = parse_input(raw_input).unwrap();
+        let expected = parse_input(&load_test_input(19, "t001")).unwrap();
+        assert_eq!(expected.len(), blueprints.len());
+        for (bp, expected_bp) in blueprints.iter().zip(expected.iter()) {
+            assert_eq!(expected_bp.id, bp.id);
+            for robot_type in RobotType::iter() {
+                assert!(bp.cost_of(robot_type) == expected_bp.cost_of(robot_type));
+            }
+        }
+    }
+
+    /// Records logged at debug level or above, captured by the test logger.
+    static CAPTURED_LOGS: Mutex<Vec<(Level, String)>> = Mutex::new(vec![]);
+
+    /// Logger used to capture the log records emitted by the solver during the tests.
+    struct CaptureLogger;
+
+    impl Log for CaptureLogger {
+        fn enabled(&self, metadata: &Metadata) -> bool {
+            metadata.level() <= Level::Debug
+        }
+
+        fn log(&self, record: &Record) {
+            if self.enabled(record.metadata()) {
+                let message = record.args().to_string();
+                CAPTURED_LOGS
+                    .lock()
+                    .unwrap()
+                    .push((record.level(), message));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    /// Tests that the "new geode total" messages are logged at debug level while simulating the
+    /// blueprints from example input 001.
+    #[test]
+    fn test_day19_logs_new_geode_totals_at_debug() {
+        static INIT: Once = Once::new();
+        INIT.call_once(|| {
+            log::set_logger(&CaptureLogger).unwrap();
+            log::set_max_level(log::LevelFilter::Debug);
+        });
+        let input = parse_input(&load_test_input(19, "t001")).unwrap();
+        assert_eq!(9, simulate_blueprint(&input[0], PART1_MINUTES_ALLOWED));
+        let logs = CAPTURED_LOGS.lock().unwrap();
+        let geode_logs = logs
+            .iter()
+            .filter(|(_, message)| message.starts_with("Day 19 - new geode total"))
+            .collect::<Vec<&(Level, String)>>();
+        assert!(!geode_logs.is_empty());
+        assert!(geode_logs.iter().all(|(level, _)| *level == Level::Debug));
+        assert!(logs.contains(&(
+            Level::Debug,
+            String::from("Day 19 - new geode total 9 for blueprint 1")
+        )));
+    }
+}
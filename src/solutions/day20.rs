@@ -0,0 +1,65 @@
+use crate::utils::sequence::CircularList;
+
+const PART2_DECRYPTION_KEY: i64 = 811589153;
+
+/// Parsed form of the AOC 2022 Day 20 input used by the solver functions.
+pub type ProblemInput = Vec<i64>;
+
+/// Parses the raw AOC 2022 Day 20 input in the format required by the solver functions.
+/// Returned value is vector of integers listed in the input file.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    raw_input
+        .trim()
+        .lines()
+        .map(|line| line.parse::<i64>().unwrap())
+        .collect::<Vec<i64>>()
+}
+
+/// Solves both parts of AOC 2022 Day 20 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day20_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 20 Part 1 // Finds the sum of the three numbers that form the grove
+/// co-ordinates.
+pub fn solve_part1(values: &[i64]) -> i64 {
+    // Conduct one round of mixing
+    let values = mix_values(values, 1);
+    // Find grove co-ordinates sum
+    find_grove_coordinates_sum(&values)
+}
+
+/// Solves AOC 2022 Day 20 Part 2 // Finds the sum of the three numbers that form the grove
+/// co-ordinates after applying the decryption key to the input values and mixing them 10 times.
+pub fn solve_part2(values: &[i64]) -> i64 {
+    // Prepare the input values list for mixing - apply decryption key
+    let values = values
+        .iter()
+        .copied()
+        .map(|val| val * PART2_DECRYPTION_KEY)
+        .collect::<Vec<i64>>();
+    // Conduct 10 rounds of mixing
+    let values = mix_values(&values, 10);
+    // Find grove co-ordinates sum
+    find_grove_coordinates_sum(&values)
+}
+
+/// Finds the sum of the three values that form the grove co-ordinates.
+fn find_grove_coordinates_sum(values: &CircularList<i64>) -> i64 {
+    [1000, 2000, 3000]
+        .iter()
+        .map(|offset| values.value_at_offset_from(&0, *offset).unwrap())
+        .sum()
+}
+
+/// Conducts the given number of rounds of value mixing, with each value moved by its own amount.
+fn mix_values(input_values: &[i64], rounds: usize) -> CircularList<i64> {
+    let mut values = CircularList::new(input_values.to_vec());
+    values.mix(rounds, |value| *value);
+    values
+}
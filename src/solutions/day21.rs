@@ -0,0 +1,432 @@
+use std::collections::HashMap;
+
+use crate::utils::math::{LinearExpr, Rational};
+use crate::utils::parsing::{parse_lines, AocError};
+
+/// Parsed form of the AOC 2022 Day 21 input used by the solver functions.
+pub type ProblemInput = HashMap<String, Operation>;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Operation {
+    Nop { value: i64 },
+    Add { left: String, right: String },
+    Subtract { left: String, right: String },
+    Multiply { left: String, right: String },
+    Divide { left: String, right: String },
+    Modulo { left: String, right: String },
+    Power { left: String, right: String },
+}
+
+/// Parses the raw Day 21 input into the hashmap of monkey names mapped to their operation.
+pub fn parse_input(raw_input: &str) -> Result<HashMap<String, Operation>, AocError> {
+    let output = parse_lines(raw_input, parse_monkey_line)?
+        .into_iter()
+        .collect::<HashMap<String, Operation>>();
+    if !output.contains_key("root") {
+        return Err(AocError::Format(String::from("no \"root\" monkey")));
+    }
+    Ok(output)
+}
+
+/// Solves both parts of AOC 2022 Day 21 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day21_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input).unwrap_or_else(|err| panic!("Day 21 - {}", err));
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Parses a single line of the Day 21 input into the monkey name and its operation. Any amount of
+/// whitespace is permitted around the colon, operator and operands.
+fn parse_monkey_line(line: &str) -> Result<(String, Operation), String> {
+    let (name, job) = match line.split_once(':') {
+        Some((name, job)) => (name.trim(), job.trim()),
+        None => return Err(String::from("missing colon after monkey name")),
+    };
+    if !is_monkey_name(name) {
+        return Err(format!("bad monkey name \"{}\"", name));
+    }
+    // Check for a monkey that yells a number
+    if let Ok(value) = job.parse::<i64>() {
+        return Ok((name.to_string(), Operation::Nop { value }));
+    }
+    // Split the job into the left operand, operator and right operand
+    let (left, operator, right) = match job.find(['+', '-', '*', '/', '%', '^']) {
+        Some(i) => (job[..i].trim(), &job[i..i + 1], job[i + 1..].trim()),
+        None => return Err(format!("bad monkey job \"{}\"", job)),
+    };
+    if !is_monkey_name(left) || !is_monkey_name(right) {
+        return Err(format!("bad operands in monkey job \"{}\"", job));
+    }
+    let left = left.to_string();
+    let right = right.to_string();
+    let op = match operator {
+        "+" => Operation::Add { left, right },
+        "-" => Operation::Subtract { left, right },
+        "*" => Operation::Multiply { left, right },
+        "/" => Operation::Divide { left, right },
+        "%" => Operation::Modulo { left, right },
+        "^" => Operation::Power { left, right },
+        _ => unreachable!(),
+    };
+    Ok((name.to_string(), op))
+}
+
+/// Checks if the given token is a valid monkey name (one or more lowercase ASCII letters).
+fn is_monkey_name(token: &str) -> bool {
+    !token.is_empty() && token.chars().all(|c| c.is_ascii_lowercase())
+}
+
+/// Solves AOC 2022 Day 21 Part 1 // Determines the number that the monkey named "root" will yell
+/// out.
+pub fn solve_part1(monkey_ops: &HashMap<String, Operation>) -> i64 {
+    determine_monkey_yell_value("root", monkey_ops).unwrap()
+}
+
+/// Solves AOC 2022 Day 21 Part 2 // Determine the number that the protagonist ("humn") needs to
+/// yell for the "root" monkey's equality check to pass.
+pub fn solve_part2(monkey_ops: &HashMap<String, Operation>) -> i64 {
+    let (left, right) = match monkey_ops.get("root").unwrap() {
+        Operation::Add { left, right }
+        | Operation::Subtract { left, right }
+        | Operation::Multiply { left, right }
+        | Operation::Divide { left, right }
+        | Operation::Modulo { left, right }
+        | Operation::Power { left, right } => (left, right),
+        _ => panic!("Bad \"root\" old op!"),
+    };
+    // Represent each side of the equality check as a linear function of the "humn" value
+    let left = determine_linear_expression(left, monkey_ops).unwrap();
+    let right = determine_linear_expression(right, monkey_ops).unwrap();
+    let humn = (left - right).solve_for(Rational::from_int(0)).unwrap();
+    match humn.to_integer() {
+        Some(value) => value,
+        None => panic!(
+            "Day 21 Part 2 - \"humn\" value is not a whole number: {}",
+            humn
+        ),
+    }
+}
+
+/// Determines the value yelled by the named monkey as a linear function of the value yelled by the
+/// protagonist ("humn"), using exact rational arithmetic. Returns None if the value is not a linear
+/// function of the "humn" value or involves division by zero.
+fn determine_linear_expression(
+    name: &str,
+    monkey_ops: &HashMap<String, Operation>,
+) -> Option<LinearExpr> {
+    if name == "humn" {
+        return Some(LinearExpr::variable());
+    }
+    let sides = |left: &str, right: &str| {
+        Some((
+            determine_linear_expression(left, monkey_ops)?,
+            determine_linear_expression(right, monkey_ops)?,
+        ))
+    };
+    match monkey_ops.get(name)? {
+        Operation::Nop { value } => Some(LinearExpr::constant(Rational::from_int(*value))),
+        Operation::Add { left, right } => {
+            let (left, right) = sides(left, right)?;
+            Some(left + right)
+        }
+        Operation::Subtract { left, right } => {
+            let (left, right) = sides(left, right)?;
+            Some(left - right)
+        }
+        Operation::Multiply { left, right } => {
+            let (left, right) = sides(left, right)?;
+            match (left.as_constant(), right.as_constant()) {
+                (Some(value), _) => Some(right.mul_const(value)),
+                (_, Some(value)) => Some(left.mul_const(value)),
+                _ => None,
+            }
+        }
+        Operation::Divide { left, right } => {
+            let (left, right) = sides(left, right)?;
+            left.div_const(right.as_constant()?)
+        }
+        // Remaining operations are only supported on values that do not depend on "humn"
+        Operation::Modulo { left, right } | Operation::Power { left, right } => {
+            let (left, right) = sides(left, right)?;
+            left.as_constant()?;
+            right.as_constant()?;
+            let value = determine_monkey_yell_value(name, monkey_ops)?;
+            Some(LinearExpr::constant(Rational::from_int(value)))
+        }
+    }
+}
+
+/// Determines the value that will be yelled by the named monkey.
+fn determine_monkey_yell_value(name: &str, monkey_ops: &HashMap<String, Operation>) -> Option<i64> {
+    match monkey_ops.get(name).unwrap() {
+        Operation::Nop { value } => Some(*value),
+        Operation::Add { left, right } => Some(
+            determine_monkey_yell_value(left, monkey_ops)?
+                + determine_monkey_yell_value(right, monkey_ops)?,
+        ),
+        Operation::Subtract { left, right } => Some(
+            determine_monkey_yell_value(left, monkey_ops)?
+                - determine_monkey_yell_value(right, monkey_ops)?,
+        ),
+        Operation::Multiply { left, right } => Some(
+            determine_monkey_yell_value(left, monkey_ops)?
+                * determine_monkey_yell_value(right, monkey_ops)?,
+        ),
+        // Division and modulo by zero result in no value being yelled
+        Operation::Divide { left, right } => determine_monkey_yell_value(left, monkey_ops)?
+            .checked_div(determine_monkey_yell_value(right, monkey_ops)?),
+        Operation::Modulo { left, right } => determine_monkey_yell_value(left, monkey_ops)?
+            .checked_rem(determine_monkey_yell_value(right, monkey_ops)?),
+        Operation::Power { left, right } => {
+            let base = determine_monkey_yell_value(left, monkey_ops)?;
+            let exponent = u32::try_from(determine_monkey_yell_value(right, monkey_ops)?).ok()?;
+            base.checked_pow(exponent)
+        }
+    }
+}
+
+/// Determines the value that will be yelled by the named monkey, using floating-point arithmetic so
+/// that any truncation from integer division is visible. Division and modulo by zero result in no
+/// value being yelled. Not used by the solvers, but useful when checking Part 2 by hand.
+pub fn determine_monkey_yell_value_f64(
+    name: &str,
+    monkey_ops: &HashMap<String, Operation>,
+) -> Option<f64> {
+    match monkey_ops.get(name).unwrap() {
+        Operation::Nop { value } => Some(*value as f64),
+        Operation::Add { left, right } => Some(
+            determine_monkey_yell_value_f64(left, monkey_ops)?
+                + determine_monkey_yell_value_f64(right, monkey_ops)?,
+        ),
+        Operation::Subtract { left, right } => Some(
+            determine_monkey_yell_value_f64(left, monkey_ops)?
+                - determine_monkey_yell_value_f64(right, monkey_ops)?,
+        ),
+        Operation::Multiply { left, right } => Some(
+            determine_monkey_yell_value_f64(left, monkey_ops)?
+                * determine_monkey_yell_value_f64(right, monkey_ops)?,
+        ),
+        Operation::Divide { left, right } => {
+            let divisor = determine_monkey_yell_value_f64(right, monkey_ops)?;
+            if divisor == 0.0 {
+                return None;
+            }
+            Some(determine_monkey_yell_value_f64(left, monkey_ops)? / divisor)
+        }
+        Operation::Modulo { left, right } => {
+            let divisor = determine_monkey_yell_value_f64(right, monkey_ops)?;
+            if divisor == 0.0 {
+                return None;
+            }
+            Some(determine_monkey_yell_value_f64(left, monkey_ops)? % divisor)
+        }
+        Operation::Power { left, right } => Some(
+            determine_monkey_yell_value_f64(left, monkey_ops)?
+                .powf(determine_monkey_yell_value_f64(right, monkey_ops)?),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::utils::runner::load_test_input;
+
+    use super::*;
+
+    /// Tests the Day 21 Part 2 solver method against example input 001.
+    #[test]
+    fn test_day21_part1_t001() {
+        let input = parse_input(&load_test_input(21, "t001")).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(152, solution);
+    }
+
+    /// Tests the Day 21 Part 2 solver method against example input 001.
+    #[test]
+    fn test_day21_part2_t001() {
+        let input = parse_input(&load_test_input(21, "t001")).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(301, solution);
+    }
+
+    /// Tests that an operation line with no whitespace around the operator is parsed.
+    #[test]
+    fn test_day21_parse_monkey_line_no_spaces() {
+        let (name, op) = parse_monkey_line("root:aaaa+bbbb").unwrap();
+        assert_eq!("root", name);
+        let expected = Operation::Add {
+            left: String::from("aaaa"),
+            right: String::from("bbbb"),
+        };
+        assert_eq!(expected, op);
+    }
+
+    /// Tests that an operation line with extra whitespace and tabs is parsed.
+    #[test]
+    fn test_day21_parse_monkey_line_extra_spaces() {
+        let (name, op) = parse_monkey_line("root:  aaaa  +  bbbb").unwrap();
+        assert_eq!("root", name);
+        let expected = Operation::Add {
+            left: String::from("aaaa"),
+            right: String::from("bbbb"),
+        };
+        assert_eq!(expected, op);
+        let (name, op) = parse_monkey_line("dbpl :\t5").unwrap();
+        assert_eq!("dbpl", name);
+        assert_eq!(Operation::Nop { value: 5 }, op);
+    }
+
+    /// Tests evaluation of monkeys using the power and modulo operators.
+    #[test]
+    fn test_day21_power_and_modulo_operators() {
+        let monkey_ops = [
+            "root: pppp % mmmm",
+            "pppp: aaaa ^ bbbb",
+            "aaaa: 3",
+            "bbbb: 4",
+            "mmmm: 5",
+        ]
+        .iter()
+        .map(|line| parse_monkey_line(line).unwrap())
+        .collect::<HashMap<String, Operation>>();
+        assert_eq!(Some(81), determine_monkey_yell_value("pppp", &monkey_ops));
+        assert_eq!(Some(1), determine_monkey_yell_value("root", &monkey_ops));
+    }
+
+    /// Tests that no linear expression is built when "humn" is an operand of the modulo or power
+    /// operators, on either side.
+    #[test]
+    fn test_day21_linear_expression_humn_under_modulo_and_power() {
+        for job in ["humn % aaaa", "aaaa % humn", "humn ^ aaaa", "aaaa ^ humn"] {
+            let monkey_ops = [
+                String::from("root: pppp + bbbb"),
+                format!("pppp: {}", job),
+                String::from("humn: 5"),
+                String::from("aaaa: 3"),
+                String::from("bbbb: 2"),
+            ]
+            .iter()
+            .map(|line| parse_monkey_line(line).unwrap())
+            .collect::<HashMap<String, Operation>>();
+            assert_eq!(None, determine_linear_expression("pppp", &monkey_ops));
+            assert_eq!(None, determine_linear_expression("root", &monkey_ops));
+        }
+    }
+
+    /// Tests that a linear expression is built for the modulo and power operators when "humn" is not
+    /// an operand.
+    #[test]
+    fn test_day21_linear_expression_modulo_and_power_without_humn() {
+        let monkey_ops = [
+            "root: pppp + humn",
+            "pppp: qqqq % aaaa",
+            "qqqq: aaaa ^ bbbb",
+            "aaaa: 3",
+            "bbbb: 2",
+            "humn: 5",
+        ]
+        .iter()
+        .map(|line| parse_monkey_line(line).unwrap())
+        .collect::<HashMap<String, Operation>>();
+        let expr = determine_linear_expression("root", &monkey_ops).unwrap();
+        assert_eq!(
+            LinearExpr::new(Rational::from_int(1), Rational::from_int(0)),
+            expr
+        );
+    }
+
+    /// Tests that a monkey performing modulo by zero does not yell a value.
+    #[test]
+    fn test_day21_modulo_by_zero() {
+        let monkey_ops = ["root: aaaa % bbbb", "aaaa: 3", "bbbb: 0"]
+            .iter()
+            .map(|line| parse_monkey_line(line).unwrap())
+            .collect::<HashMap<String, Operation>>();
+        assert_eq!(None, determine_monkey_yell_value("root", &monkey_ops));
+    }
+
+    /// Tests that a monkey raising a value to a negative or overflowing power does not yell a value.
+    #[test]
+    fn test_day21_power_out_of_range() {
+        // Exponent is calculated as 1 - 2 = -1 and 64 - 0 = 64 respectively
+        for (cccc, dddd) in [(1, 2), (64, 0)] {
+            let monkey_ops = [
+                String::from("root: aaaa ^ bbbb"),
+                String::from("aaaa: 2"),
+                String::from("bbbb: cccc - dddd"),
+                format!("cccc: {}", cccc),
+                format!("dddd: {}", dddd),
+            ]
+            .iter()
+            .map(|line| parse_monkey_line(line).unwrap())
+            .collect::<HashMap<String, Operation>>();
+            assert_eq!(None, determine_monkey_yell_value("root", &monkey_ops));
+        }
+    }
+
+    /// Tests that the integer and floating-point evaluations differ for a non-exact division.
+    #[test]
+    fn test_day21_f64_evaluation_non_exact_division() {
+        let monkey_ops = [
+            "root: dddd * bbbb",
+            "dddd: aaaa / bbbb",
+            "aaaa: 7",
+            "bbbb: 2",
+        ]
+        .iter()
+        .map(|line| parse_monkey_line(line).unwrap())
+        .collect::<HashMap<String, Operation>>();
+        assert_eq!(Some(6), determine_monkey_yell_value("root", &monkey_ops));
+        assert_eq!(
+            Some(7.0),
+            determine_monkey_yell_value_f64("root", &monkey_ops)
+        );
+        assert_eq!(
+            Some(3.5),
+            determine_monkey_yell_value_f64("dddd", &monkey_ops)
+        );
+    }
+
+    /// Tests that the floating-point evaluation agrees with the integer evaluation on example input
+    /// 001, which only contains exact divisions.
+    #[test]
+    fn test_day21_f64_evaluation_t001() {
+        let input = parse_input(&load_test_input(21, "t001")).unwrap();
+        assert_eq!(Some(152.0), determine_monkey_yell_value_f64("root", &input));
+    }
+
+    /// Tests that a malformed line results in a parse error reporting the line number.
+    #[test]
+    fn test_day21_parse_input_malformed_line() {
+        let raw_input = "root: aaaa + bbbb\naaaa: 5\nbbbb: 3 + 4\n";
+        match parse_input(raw_input) {
+            Err(AocError::Parse { line, .. }) => assert_eq!(3, line),
+            _ => panic!("Expected a parse error"),
+        }
+    }
+
+    /// Tests that input without a "root" monkey results in a format error.
+    #[test]
+    fn test_day21_parse_input_missing_root() {
+        let raw_input = "aaaa: bbbb * cccc\nbbbb: 5\ncccc: 3\n";
+        assert!(matches!(parse_input(raw_input), Err(AocError::Format(_))));
+    }
+
+    /// Tests that the linear expression for the side of the "root" check with the "humn" monkey in
+    /// example input 001 is built correctly.
+    #[test]
+    fn test_day21_determine_linear_expression_t001() {
+        let input = parse_input(&load_test_input(21, "t001")).unwrap();
+        // pppw = cczh / lfqf = (sllz + lgvd) / 4 = (4 + ljgn * ptdq) / 4 = (4 + 2 * (humn - 3)) / 4
+        let expr = determine_linear_expression("pppw", &input).unwrap();
+        assert_eq!(
+            LinearExpr::new(Rational::new(1, 2), Rational::new(-1, 2)),
+            expr
+        );
+        let sjmn = determine_linear_expression("sjmn", &input).unwrap();
+        assert_eq!(Some(Rational::from_int(150)), sjmn.as_constant());
+    }
+}
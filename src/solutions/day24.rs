@@ -0,0 +1,246 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::utils::cartography::{CardinalDirection, MinMax2D, Point2D};
+
+/// Type declaration to simply input parser and part solver function signatures.
+pub type ProblemInput = (Point2D, Point2D, MinMax2D, BlizzardState);
+
+/// Represents a blizzard map state.
+#[derive(Clone)]
+pub struct BlizzardState {
+    minutes: u64,
+    map: HashMap<Point2D, Vec<CardinalDirection>>,
+    locs: HashSet<Point2D>,
+}
+
+/// Parses the raw AOC 2022 Day 24 input in the format required by the solver functions.
+/// Returned value is tuple containing the: start location, end location, minmax bounding area for
+/// the blizzards and the initial blizzard state.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    let mut start_loc: Option<Point2D> = None;
+    let mut end_loc: Option<Point2D> = None;
+    let mut blizzard_locs: HashMap<Point2D, Vec<CardinalDirection>> = HashMap::new();
+    let mut max_x = 0;
+    let mut max_y = 0;
+    for (y, line) in raw_input.trim().lines().enumerate() {
+        if y > max_y {
+            max_y = y;
+        }
+        for (x, tile) in line.chars().enumerate() {
+            if x > max_x {
+                max_x = x;
+            }
+            if y == 0 && tile == '.' {
+                start_loc = Some(Point2D::new(x as i64, y as i64));
+                break;
+            }
+            let loc = Point2D::new(x as i64, y as i64);
+            match tile {
+                '^' => _ = blizzard_locs.insert(loc, vec![CardinalDirection::North]),
+                '>' => _ = blizzard_locs.insert(loc, vec![CardinalDirection::East]),
+                'v' => _ = blizzard_locs.insert(loc, vec![CardinalDirection::South]),
+                '<' => _ = blizzard_locs.insert(loc, vec![CardinalDirection::West]),
+                '.' => end_loc = Some(loc),
+                _ => (),
+            };
+        }
+    }
+    let minmax = MinMax2D::new(1, max_x as i64 - 1, 1, max_y as i64 - 1);
+    let blizzard_state = BlizzardState {
+        minutes: 0,
+        map: blizzard_locs.clone(),
+        locs: blizzard_locs.keys().copied().collect::<HashSet<Point2D>>(),
+    };
+    (start_loc.unwrap(), end_loc.unwrap(), minmax, blizzard_state)
+}
+
+/// Solves both parts of AOC 2022 Day 24 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day24_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 24 Part 1 // Determines the fewest number of minutes required to avoid the
+/// blizzards and reach the goal.
+pub fn solve_part1(problem_input: &ProblemInput) -> u64 {
+    let (start_loc, end_loc, minmax, initial_blizzard_state) = problem_input;
+    let target_locs = VecDeque::from([*end_loc]);
+    navigate_blizzard(start_loc, &target_locs, initial_blizzard_state, minmax).unwrap()
+}
+
+/// Solves AOC 2022 Day 24 Part 2 // Determines the fewest number of minutes required to reach the
+/// goal location, return to the start location, then reach the goal location again.
+pub fn solve_part2(problem_input: &ProblemInput) -> u64 {
+    let (start_loc, end_loc, minmax, initial_blizzard_state) = problem_input;
+    let target_locs = VecDeque::from([*end_loc, *start_loc, *end_loc]);
+    navigate_blizzard(start_loc, &target_locs, initial_blizzard_state, minmax).unwrap()
+}
+
+/// Navigates the blizzard by aiming for the target locations. Return the fewest number of minutes
+/// required to visit each of the target locations in order.
+fn navigate_blizzard(
+    start_loc: &Point2D,
+    target_locs: &VecDeque<Point2D>,
+    initial_blizzard_state: &BlizzardState,
+    minmax: &MinMax2D,
+) -> Option<u64> {
+    // Initialise the collection of locations that are exceptions to the minmax bounding area
+    let minmax_exceptions: HashSet<Point2D> = generate_minmax_exceptions(start_loc, target_locs);
+    let mut visit_queue: VecDeque<(u64, Point2D)> = VecDeque::from([(0, *start_loc)]);
+    let mut target_locs = target_locs.clone();
+    // Initialise the blizzard state
+    let mut blizzard_state = initial_blizzard_state.clone();
+    // Track the different locations visited at different times
+    let mut visited: HashSet<(u64, Point2D)> = HashSet::new();
+    visited.insert((0, *start_loc));
+    while !visit_queue.is_empty() && !target_locs.is_empty() {
+        // Get the next location to visit
+        let (minutes, loc) = visit_queue.pop_front().unwrap();
+        // Update the blizzard state
+        if blizzard_state.minutes == minutes {
+            blizzard_state = update_blizzard_state(&blizzard_state, minmax);
+        }
+        let mut target_reached = false;
+        for next_loc in get_valid_next_locs(&loc, minmax, &blizzard_state, &minmax_exceptions) {
+            // Check if the end location has been reached
+            if next_loc == *target_locs.front().unwrap() {
+                target_reached = true;
+                // Discard the visit queue when a target location is reached
+                target_locs.pop_front();
+                visit_queue = VecDeque::new();
+                visited = HashSet::new();
+                // The navigation is finished when there are no more target locations to visit
+                if target_locs.is_empty() {
+                    return Some(minutes + 1);
+                }
+            }
+            // Check if the next location has not already been visited in the next minute
+            let next_visit = (minutes + 1, next_loc);
+            if !visited.contains(&next_visit) {
+                visit_queue.push_back(next_visit);
+                visited.insert(next_visit);
+            }
+            // Skip other move options if a target location has been reached
+            if target_reached {
+                break;
+            }
+        }
+    }
+    None
+}
+
+fn generate_minmax_exceptions(
+    start_loc: &Point2D,
+    target_locs: &VecDeque<Point2D>,
+) -> HashSet<Point2D> {
+    let mut output = HashSet::new();
+    output.insert(*start_loc);
+    output.extend(target_locs);
+    output
+}
+
+/// Gets the valid next locations from the current location.
+fn get_valid_next_locs(
+    loc: &Point2D,
+    minmax: &MinMax2D,
+    blizzard_state: &BlizzardState,
+    wall_openings: &HashSet<Point2D>,
+) -> Vec<Point2D> {
+    let mut output: Vec<Point2D> = vec![];
+    for (dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0), (0, 0)] {
+        let next_loc = loc.peek_move_point(dx, dy);
+        if wall_openings.contains(&next_loc) {
+            output.push(next_loc);
+            continue;
+        }
+        if !minmax.contains_point(&next_loc) {
+            continue;
+        }
+        if blizzard_state.locs.contains(&next_loc) {
+            continue;
+        }
+        output.push(next_loc);
+    }
+    output
+}
+
+/// Updates the blizzard state by moving each of the blizzards in their set direction and wrapping
+/// around any blizzards that reach the walls.
+fn update_blizzard_state(blizzard_state: &BlizzardState, minmax: &MinMax2D) -> BlizzardState {
+    let mut new_blizzard_map: HashMap<Point2D, Vec<CardinalDirection>> = HashMap::new();
+    for (loc, blizzards) in blizzard_state.map.iter() {
+        for bliz in blizzards {
+            let new_loc = match bliz {
+                CardinalDirection::North => {
+                    let mut temp_loc = loc.peek_move_point(0, -1);
+                    if temp_loc.y() < minmax.min_y() {
+                        temp_loc.set_y(minmax.max_y());
+                    }
+                    temp_loc
+                }
+                CardinalDirection::East => {
+                    let mut temp_loc = loc.peek_move_point(1, 0);
+                    if temp_loc.x() > minmax.max_x() {
+                        temp_loc.set_x(minmax.min_x());
+                    }
+                    temp_loc
+                }
+                CardinalDirection::South => {
+                    let mut temp_loc = loc.peek_move_point(0, 1);
+                    if temp_loc.y() > minmax.max_y() {
+                        temp_loc.set_y(minmax.min_y());
+                    }
+                    temp_loc
+                }
+                CardinalDirection::West => {
+                    let mut temp_loc = loc.peek_move_point(-1, 0);
+                    if temp_loc.x() < minmax.min_x() {
+                        temp_loc.set_x(minmax.max_x());
+                    }
+                    temp_loc
+                }
+            };
+            if let Entry::Vacant(e) = new_blizzard_map.entry(new_loc) {
+                e.insert(vec![*bliz]);
+            } else {
+                new_blizzard_map.get_mut(&new_loc).unwrap().push(*bliz);
+            }
+        }
+    }
+    BlizzardState {
+        minutes: blizzard_state.minutes + 1,
+        map: new_blizzard_map.clone(),
+        locs: new_blizzard_map
+            .keys()
+            .copied()
+            .collect::<HashSet<Point2D>>(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::utils::runner::load_test_input;
+
+    use super::*;
+
+    /// Tests the Day 24 Part 1 solver method against example input 001.
+    #[test]
+    fn test_day24_part1_t001() {
+        let input = parse_input(&load_test_input(24, "t001"));
+        let solution = solve_part1(&input);
+        assert_eq!(18, solution);
+    }
+
+    /// Tests the Day 24 Part 2 solver method against example input 001.
+    #[test]
+    fn test_day24_part2_t001() {
+        let input = parse_input(&load_test_input(24, "t001"));
+        let solution = solve_part2(&input);
+        assert_eq!(54, solution);
+    }
+}
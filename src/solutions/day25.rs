@@ -0,0 +1,52 @@
+use crate::utils::math::{from_snafu, to_snafu};
+
+/// Parsed form of the AOC 2022 Day 25 input used by the solver functions.
+pub type ProblemInput = Vec<String>;
+
+/// Parses the raw AOC 2022 Day 25 input in the format required by the solver functions.
+/// Returned value is vector of SNAFU number strings given in the input file.
+pub fn parse_input(raw_input: &str) -> ProblemInput {
+    raw_input
+        .trim()
+        .lines()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<String>>()
+}
+
+/// Solves both parts of AOC 2022 Day 25 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day25_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 25 Part 1 // Determines the SNAFU number that needs to be supplied to Bob's
+/// console.
+pub fn solve_part1(snafu_numbers: &[String]) -> String {
+    let snafu_sum: i64 = snafu_numbers.iter().map(|s| from_snafu(s)).sum();
+    to_snafu(snafu_sum)
+}
+
+/// Solves AOC 2022 Day 25 Part 2 // Christmas is saved!
+pub fn solve_part2(_input: &[String]) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use crate::utils::runner::load_test_input;
+
+    use super::*;
+
+    /// Tests the Day 25 Part 1 solver method against example input 001.
+    #[test]
+    fn test_day25_part1_t001() {
+        let input = parse_input(&load_test_input(25, "t001"));
+        let solution = solve_part1(&input);
+        assert_eq!(String::from("2=-1=0"), solution);
+    }
+}
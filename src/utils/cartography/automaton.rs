@@ -0,0 +1,42 @@
+use super::{Grid, Point2D};
+
+/// Produces the next generation of a cellular automaton by applying the rule to every cell of the
+/// grid. The rule is given the current generation and the location of the cell, and returns the
+/// new value of the cell. All cells are updated simultaneously, since the new values are written
+/// to a separate grid rather than the grid being read by the rule.
+pub fn step_automaton<T: Clone>(grid: &Grid<T>, rule: impl Fn(&Grid<T>, &Point2D) -> T) -> Grid<T> {
+    let rows = (0..grid.height() as i64)
+        .map(|y| {
+            (0..grid.width() as i64)
+                .map(|x| rule(grid, &Point2D::new(x, y)))
+                .collect::<Vec<T>>()
+        })
+        .collect::<Vec<Vec<T>>>();
+    Grid::from_rows(rows)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Applies the rules of Conway's Game of Life to the given cell.
+    fn life_rule(grid: &Grid<bool>, point: &Point2D) -> bool {
+        let alive = grid.count_surrounding_matching(point, |cell| *cell);
+        matches!((grid.get(point), alive), (Some(true), 2) | (_, 3))
+    }
+
+    /// Tests that a blinker in Conway's Game of Life oscillates with a period of two generations.
+    #[test]
+    fn test_step_automaton_life_blinker() {
+        let mut horizontal = Grid::new(5, 5, false);
+        let mut vertical = Grid::new(5, 5, false);
+        for i in 1..=3 {
+            horizontal.set(&Point2D::new(i, 2), true);
+            vertical.set(&Point2D::new(2, i), true);
+        }
+        let gen1 = step_automaton(&horizontal, life_rule);
+        let gen2 = step_automaton(&gen1, life_rule);
+        assert_eq!(vertical, gen1);
+        assert_eq!(horizontal, gen2);
+    }
+}
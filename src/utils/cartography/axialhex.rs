@@ -0,0 +1,114 @@
+use super::Point3D;
+
+/// Represents a single cell of a hexagonal grid using axial coordinates (q, r). The implied third
+/// cube coordinate is s = -q - r.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct AxialHex {
+    q: i64,
+    r: i64,
+}
+
+impl AxialHex {
+    /// Creates a new axial hex coordinate.
+    pub fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    /// Creates a new axial hex coordinate from the given cube coordinate, with the x-, y- and
+    /// z-values of the point taken as the q, r and s cube coordinates. Returns None if the
+    /// coordinates do not sum to zero (not a valid cube coordinate).
+    pub fn from_cube(cube: &Point3D) -> Option<AxialHex> {
+        if cube.x() + cube.y() + cube.z() != 0 {
+            return None;
+        }
+        Some(AxialHex::new(cube.x(), cube.y()))
+    }
+
+    /// Gets the value of the q-coordinate.
+    pub fn q(&self) -> i64 {
+        self.q
+    }
+
+    /// Gets the value of the r-coordinate.
+    pub fn r(&self) -> i64 {
+        self.r
+    }
+
+    /// Gets the value of the implied s-coordinate.
+    pub fn s(&self) -> i64 {
+        -self.q - self.r
+    }
+
+    /// Converts the axial hex coordinate to the equivalent cube coordinate, with the q, r and s cube
+    /// coordinates given as the x-, y- and z-values of the point.
+    pub fn to_cube(&self) -> Point3D {
+        Point3D::new(self.q, self.r, self.s())
+    }
+
+    /// Gets the six hex cells adjacent to the current cell.
+    pub fn neighbours(&self) -> Vec<AxialHex> {
+        vec![
+            AxialHex::new(self.q + 1, self.r),
+            AxialHex::new(self.q + 1, self.r - 1),
+            AxialHex::new(self.q, self.r - 1),
+            AxialHex::new(self.q - 1, self.r),
+            AxialHex::new(self.q - 1, self.r + 1),
+            AxialHex::new(self.q, self.r + 1),
+        ]
+    }
+
+    /// Calculates the number of steps between the current cell and the other cell.
+    pub fn distance(&self, other: &AxialHex) -> u64 {
+        let dq = (self.q - other.q).unsigned_abs();
+        let dr = (self.r - other.r).unsigned_abs();
+        let ds = (self.s() - other.s()).unsigned_abs();
+        (dq + dr + ds) / 2
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    /// Tests that the origin has six distinct neighbours, each one step away.
+    #[test]
+    fn test_axialhex_neighbours_of_origin() {
+        let origin = AxialHex::new(0, 0);
+        let neighbours = origin.neighbours();
+        let expected = HashSet::from([
+            AxialHex::new(1, 0),
+            AxialHex::new(1, -1),
+            AxialHex::new(0, -1),
+            AxialHex::new(-1, 0),
+            AxialHex::new(-1, 1),
+            AxialHex::new(0, 1),
+        ]);
+        assert_eq!(
+            expected,
+            neighbours.iter().copied().collect::<HashSet<AxialHex>>()
+        );
+        assert!(neighbours.iter().all(|hex| origin.distance(hex) == 1));
+    }
+
+    /// Tests the hex distance between two cells.
+    #[test]
+    fn test_axialhex_distance() {
+        let a = AxialHex::new(-2, 3);
+        let b = AxialHex::new(3, -1);
+        assert_eq!(5, a.distance(&b));
+        assert_eq!(5, b.distance(&a));
+        assert_eq!(0, a.distance(&a));
+    }
+
+    /// Tests conversion to and from cube coordinates.
+    #[test]
+    fn test_axialhex_cube_conversion() {
+        let hex = AxialHex::new(2, -5);
+        let cube = hex.to_cube();
+        assert_eq!(Point3D::new(2, -5, 3), cube);
+        assert_eq!(Some(hex), AxialHex::from_cube(&cube));
+        assert_eq!(None, AxialHex::from_cube(&Point3D::new(1, 1, 1)));
+    }
+}
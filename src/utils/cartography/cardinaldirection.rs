@@ -1,5 +1,5 @@
 /// Represents the cardinal directions on a map.
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum CardinalDirection {
     North,
     East,
@@ -8,6 +8,19 @@ pub enum CardinalDirection {
 }
 
 impl CardinalDirection {
+    /// Gets the cardinal direction represented by the given character, as used in movement
+    /// instructions. Letters (U/D/L/R), arrows (^/v/</>) and compass points (N/S/E/W) are
+    /// recognised, with up being north. Returns None for any other character.
+    pub fn from_char(c: char) -> Option<CardinalDirection> {
+        match c {
+            'U' | '^' | 'N' => Some(CardinalDirection::North),
+            'R' | '>' | 'E' => Some(CardinalDirection::East),
+            'D' | 'v' | 'S' => Some(CardinalDirection::South),
+            'L' | '<' | 'W' => Some(CardinalDirection::West),
+            _ => None,
+        }
+    }
+
     /// Determines the cardinal direction resulting from rotating from the current direction by 90
     /// degrees in the clockwise direction.
     pub fn rotate90_clockwise(&self) -> CardinalDirection {
@@ -30,3 +43,32 @@ impl CardinalDirection {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that each recognised character maps to the correct direction.
+    #[test]
+    fn test_cardinal_direction_from_char() {
+        let expected = [
+            ("U^N", CardinalDirection::North),
+            ("R>E", CardinalDirection::East),
+            ("DvS", CardinalDirection::South),
+            ("L<W", CardinalDirection::West),
+        ];
+        for (chars, dirn) in expected {
+            for c in chars.chars() {
+                assert_eq!(Some(dirn), CardinalDirection::from_char(c), "char {}", c);
+            }
+        }
+    }
+
+    /// Tests that unrecognised characters do not map to a direction.
+    #[test]
+    fn test_cardinal_direction_from_char_unknown() {
+        for c in ['u', 'V', 'x', ' ', '.', 'n'] {
+            assert_eq!(None, CardinalDirection::from_char(c), "char {}", c);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use super::{flood_fill_3d, MinMax3D, Point3D};
+
+/// Calculates the surface area of the given set of unit cubes, by counting the faces of the cubes
+/// that are not shared with another cube in the set.
+pub fn surface_area(cubes: &HashSet<Point3D>) -> u64 {
+    cubes
+        .iter()
+        .flat_map(|cube| cube.get_adjacent_points())
+        .filter(|adj_cube| !cubes.contains(adj_cube))
+        .count() as u64
+}
+
+/// Calculates the exterior surface area of the given set of unit cubes, by counting only the faces
+/// of the cubes that touch the air outside of the cubes. The outside air is found by flood filling
+/// the air within the bounding box of the cubes padded by one on each side, so faces facing air
+/// pockets trapped inside the cubes are not counted.
+pub fn exterior_surface_area(cubes: &HashSet<Point3D>) -> u64 {
+    let bounds = match padded_bounds(cubes) {
+        Some(bounds) => bounds,
+        None => return 0,
+    };
+    let start = Point3D::new(bounds.min_x(), bounds.min_y(), bounds.min_z());
+    let exterior = flood_fill_3d(start, |p| bounds.contains_point(p) && !cubes.contains(p));
+    exterior
+        .iter()
+        .flat_map(|air| air.get_adjacent_points())
+        .filter(|adj_cube| cubes.contains(adj_cube))
+        .count() as u64
+}
+
+/// Determines the bounding box containing the given cubes, padded by one on each side so that none
+/// of the cubes are at the edge. Returns None if there are no cubes.
+fn padded_bounds(cubes: &HashSet<Point3D>) -> Option<MinMax3D> {
+    let min_x = cubes.iter().map(|cube| cube.x()).min()? - 1;
+    let max_x = cubes.iter().map(|cube| cube.x()).max()? + 1;
+    let min_y = cubes.iter().map(|cube| cube.y()).min()? - 1;
+    let max_y = cubes.iter().map(|cube| cube.y()).max()? + 1;
+    let min_z = cubes.iter().map(|cube| cube.z()).min()? - 1;
+    let max_z = cubes.iter().map(|cube| cube.z()).max()? + 1;
+    Some(MinMax3D::new(min_x, max_x, min_y, max_y, min_z, max_z))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that a single cube has a surface area of 6.
+    #[test]
+    fn test_surface_area_single_cube() {
+        let cubes = HashSet::from([Point3D::new(1, 1, 1)]);
+        assert_eq!(6, surface_area(&cubes));
+        assert_eq!(0, surface_area(&HashSet::new()));
+    }
+
+    /// Tests that two adjacent cubes have a surface area of 10 (12 faces minus the 2 shared).
+    #[test]
+    fn test_surface_area_adjacent_cubes() {
+        let cubes = HashSet::from([Point3D::new(1, 1, 1), Point3D::new(2, 1, 1)]);
+        assert_eq!(10, surface_area(&cubes));
+        let apart = HashSet::from([Point3D::new(1, 1, 1), Point3D::new(3, 1, 1)]);
+        assert_eq!(12, surface_area(&apart));
+    }
+
+    /// Tests that the faces around the cavity inside a hollow 3x3x3 shell are excluded from the
+    /// exterior surface area, but included in the total surface area.
+    #[test]
+    fn test_exterior_surface_area_hollow_shell() {
+        let shell = (0..27)
+            .map(|i| Point3D::new(i % 3, (i / 3) % 3, i / 9))
+            .filter(|p| *p != Point3D::new(1, 1, 1))
+            .collect::<HashSet<Point3D>>();
+        assert_eq!(26, shell.len());
+        assert_eq!(60, surface_area(&shell));
+        assert_eq!(54, exterior_surface_area(&shell));
+    }
+
+    /// Tests that the exterior surface area matches the total surface area when there are no
+    /// trapped air pockets.
+    #[test]
+    fn test_exterior_surface_area_no_cavity() {
+        let cubes = HashSet::from([Point3D::new(1, 1, 1), Point3D::new(2, 1, 1)]);
+        assert_eq!(10, exterior_surface_area(&cubes));
+        assert_eq!(0, exterior_surface_area(&HashSet::new()));
+    }
+}
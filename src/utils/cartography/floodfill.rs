@@ -0,0 +1,140 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::{Point2D, Point3D};
+
+/// Used to specify which neighbouring points are considered connected during a flood fill.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Connectivity {
+    /// Only the four orthogonally adjacent points are connected.
+    Four,
+    /// The four orthogonally adjacent points and the four diagonal points are connected.
+    Eight,
+}
+
+impl Connectivity {
+    /// Gets the points connected to the given point under this connectivity mode.
+    fn neighbours(&self, point: &Point2D) -> Vec<Point2D> {
+        match self {
+            Connectivity::Four => point.get_adjacent_points(),
+            Connectivity::Eight => point.get_surrounding_points(),
+        }
+    }
+}
+
+/// Flood fills from the start point, moving up, down, left and right to any point for which the
+/// passable function returns true. Returned value is the set of points reached, including the
+/// start point (if it is passable). The passable function must bound the fill, otherwise it will
+/// not terminate.
+pub fn flood_fill(start: Point2D, passable: impl Fn(&Point2D) -> bool) -> HashSet<Point2D> {
+    flood_fill_with_connectivity(start, Connectivity::Four, passable)
+}
+
+/// Flood fills from the start point in the same way as flood_fill(), but with the points connected
+/// to each point determined by the given connectivity mode.
+pub fn flood_fill_with_connectivity(
+    start: Point2D,
+    connectivity: Connectivity,
+    passable: impl Fn(&Point2D) -> bool,
+) -> HashSet<Point2D> {
+    let mut filled: HashSet<Point2D> = HashSet::new();
+    if !passable(&start) {
+        return filled;
+    }
+    let mut visit_queue: VecDeque<Point2D> = VecDeque::from([start]);
+    filled.insert(start);
+    while let Some(current) = visit_queue.pop_front() {
+        for next in connectivity.neighbours(&current) {
+            if !filled.contains(&next) && passable(&next) {
+                filled.insert(next);
+                visit_queue.push_back(next);
+            }
+        }
+    }
+    filled
+}
+
+/// Flood fills from the start point in three dimensions, moving along each axis (not including
+/// diagonals) to any point for which the passable function returns true. Returned value is the set
+/// of points reached, including the start point (if it is passable). The passable function must
+/// bound the fill, otherwise it will not terminate.
+pub fn flood_fill_3d(start: Point3D, passable: impl Fn(&Point3D) -> bool) -> HashSet<Point3D> {
+    let mut filled: HashSet<Point3D> = HashSet::new();
+    if !passable(&start) {
+        return filled;
+    }
+    let mut visit_queue: VecDeque<Point3D> = VecDeque::from([start]);
+    filled.insert(start);
+    while let Some(current) = visit_queue.pop_front() {
+        for next in current.get_adjacent_points() {
+            if !filled.contains(&next) && passable(&next) {
+                filled.insert(next);
+                visit_queue.push_back(next);
+            }
+        }
+    }
+    filled
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Creates a passable function for the given map, where '.' cells are open and everything
+    /// outside the map is blocked.
+    fn open_cells(rows: &[&str]) -> impl Fn(&Point2D) -> bool {
+        let open = rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .filter(|(_, c)| *c == '.')
+                    .map(move |(x, _)| Point2D::new(x as i64, y as i64))
+            })
+            .collect::<HashSet<Point2D>>();
+        move |point| open.contains(point)
+    }
+
+    /// Tests that two regions touching only at a diagonal are kept separate when 4-connected.
+    #[test]
+    fn test_flood_fill_diagonal_gap_four_connected() {
+        let passable = open_cells(&["..#", "..#", "##."]);
+        let filled = flood_fill(Point2D::new(0, 0), &passable);
+        assert_eq!(4, filled.len());
+        assert!(!filled.contains(&Point2D::new(2, 2)));
+        let filled =
+            flood_fill_with_connectivity(Point2D::new(2, 2), Connectivity::Four, &passable);
+        assert_eq!(HashSet::from([Point2D::new(2, 2)]), filled);
+    }
+
+    /// Tests that two regions touching only at a diagonal are merged when 8-connected.
+    #[test]
+    fn test_flood_fill_diagonal_gap_eight_connected() {
+        let passable = open_cells(&["..#", "..#", "##."]);
+        let filled =
+            flood_fill_with_connectivity(Point2D::new(0, 0), Connectivity::Eight, &passable);
+        assert_eq!(5, filled.len());
+        assert!(filled.contains(&Point2D::new(2, 2)));
+    }
+
+    /// Tests that nothing is filled if the start point is not passable.
+    #[test]
+    fn test_flood_fill_blocked_start() {
+        let passable = open_cells(&["#."]);
+        assert!(flood_fill(Point2D::new(0, 0), passable).is_empty());
+    }
+
+    /// Tests that a 3D flood fill stays within its bounding box and does not pass through walls.
+    #[test]
+    fn test_flood_fill_3d_bounded() {
+        // A 3x3x3 box split in two by a wall across the plane x = 1
+        let passable = |p: &Point3D| {
+            let in_box = [p.x(), p.y(), p.z()].iter().all(|v| (0..3).contains(v));
+            in_box && p.x() != 1
+        };
+        let filled = flood_fill_3d(Point3D::new(0, 0, 0), passable);
+        assert_eq!(9, filled.len());
+        assert!(filled.iter().all(|p| p.x() == 0));
+        assert!(flood_fill_3d(Point3D::new(1, 0, 0), passable).is_empty());
+    }
+}
@@ -0,0 +1,534 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::{dimensions, CardinalDirection, Point2D};
+
+/// Represents a dense rectangular grid of values. The top-left cell of the grid is located at
+/// (0,0), with x-values increasing to the right and y-values increasing downward. The flips and
+/// rotations act on the grid as it is displayed (row 0 at the top), so clockwise rotation in this
+/// y-down convention appears counter-clockwise in the y-up (maths) convention.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a new grid with the given dimensions, with every cell set to the default value.
+    pub fn new(width: usize, height: usize, default: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![default; width * height],
+        }
+    }
+
+    /// Creates a new grid from the given rows of values. Panics if the rows are not all the same
+    /// length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            panic!("Grid rows must all be the same length!");
+        }
+        Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect::<Vec<T>>(),
+        }
+    }
+
+    /// Creates a new grid covering the bounding box of the points in the given map. The cell at the
+    /// minimum x- and y-values of the map is located at (0,0) in the grid, and cells without a
+    /// corresponding point in the map are set to the default value.
+    pub fn from_points(map: &HashMap<Point2D, T>, default: T) -> Self {
+        let (min_x, min_y, max_x, max_y) = match dimensions(map) {
+            Some(bounds) => bounds,
+            None => return Grid::new(0, 0, default),
+        };
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+        let mut grid = Grid::new(width, height, default);
+        for (point, value) in map {
+            let loc = Point2D::new(point.x() - min_x, point.y() - min_y);
+            grid.set(&loc, value.clone());
+        }
+        grid
+    }
+
+    /// Returns a new grid with the rows and columns of the current grid swapped.
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Returns a new grid with the current grid mirrored left-to-right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        self.remap(self.width, self.height, |x, y| (self.width - 1 - x, y))
+    }
+
+    /// Returns a new grid with the current grid mirrored top-to-bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        self.remap(self.width, self.height, |x, y| (x, self.height - 1 - y))
+    }
+
+    /// Returns a new grid with the current grid rotated by 90 degrees in the clockwise direction.
+    pub fn rotate_cw_90(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    /// Returns a new grid with the current grid rotated by 90 degrees in the counter-clockwise
+    /// direction.
+    pub fn rotate_ccw_90(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+
+    /// Builds a new grid with the given dimensions, where the value of each cell is copied from
+    /// the location in the current grid given by the source mapping function.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Grid<T> {
+        let mut cells: Vec<T> = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let (src_x, src_y) = source(x, y);
+                cells.push(self.cells[src_y * self.width + src_x].clone());
+            }
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Gets the points whose values differ between the current grid and the other grid. If the
+    /// grids have different dimensions, points only located within one of the grids are included.
+    pub fn diff(&self, other: &Grid<T>) -> HashSet<Point2D> {
+        let width = self.width.max(other.width) as i64;
+        let height = self.height.max(other.height) as i64;
+        let mut changed: HashSet<Point2D> = HashSet::new();
+        for y in 0..height {
+            for x in 0..width {
+                let point = Point2D::new(x, y);
+                if self.get(&point) != other.get(&point) {
+                    changed.insert(point);
+                }
+            }
+        }
+        changed
+    }
+}
+
+impl<T> Grid<T> {
+    /// Gets the width of the grid.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the height of the grid.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Checks if the given point is located within the bounds of the grid.
+    pub fn contains_point(&self, point: &Point2D) -> bool {
+        point.x() >= 0
+            && point.y() >= 0
+            && (point.x() as usize) < self.width
+            && (point.y() as usize) < self.height
+    }
+
+    /// Gets a reference to the value at the given point, if the point is within the grid.
+    pub fn get(&self, point: &Point2D) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    /// Gets a mutable reference to the value at the given point, if the point is within the grid.
+    pub fn get_mut(&mut self, point: &Point2D) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Updates the value at the given point. Returns false if the point is outside of the grid.
+    pub fn set(&mut self, point: &Point2D, value: T) -> bool {
+        match self.index_of(point) {
+            Some(i) => {
+                self.cells[i] = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Gets the values in the given row of the grid, from left to right. Returns an empty vector if
+    /// the row is outside of the grid.
+    pub fn row(&self, y: i64) -> Vec<&T> {
+        if y < 0 || y as usize >= self.height {
+            return vec![];
+        }
+        let start = y as usize * self.width;
+        self.cells[start..start + self.width].iter().collect()
+    }
+
+    /// Gets the values in the given column of the grid, from top to bottom. Returns an empty vector
+    /// if the column is outside of the grid.
+    pub fn column(&self, x: i64) -> Vec<&T> {
+        if x < 0 || x as usize >= self.width {
+            return vec![];
+        }
+        self.cells
+            .iter()
+            .skip(x as usize)
+            .step_by(self.width)
+            .collect()
+    }
+
+    /// Iterates over the cells outward from the given point in the given direction, stopping at the
+    /// edge of the grid. The cell at the starting point itself is not included.
+    pub fn ray(
+        &self,
+        from: &Point2D,
+        dirn: CardinalDirection,
+    ) -> impl Iterator<Item = (Point2D, &T)> {
+        std::iter::successors(Some(*from), move |point| Some(point.step(dirn)))
+            .skip(1)
+            .map_while(move |point| self.get(&point).map(|value| (point, value)))
+    }
+
+    /// Counts the cells orthogonally adjacent to the given point (up, down, left and right) that are
+    /// within the grid and whose values match the predicate.
+    pub fn count_orthogonal_matching(&self, point: &Point2D, pred: impl Fn(&T) -> bool) -> usize {
+        point
+            .get_adjacent_points()
+            .iter()
+            .filter_map(|p| self.get(p))
+            .filter(|value| pred(value))
+            .count()
+    }
+
+    /// Counts the cells surrounding the given point (including diagonals) that are within the grid
+    /// and whose values match the predicate.
+    pub fn count_surrounding_matching(&self, point: &Point2D, pred: impl Fn(&T) -> bool) -> usize {
+        point
+            .get_surrounding_points()
+            .iter()
+            .filter_map(|p| self.get(p))
+            .filter(|value| pred(value))
+            .count()
+    }
+
+    /// Iterates over the cells of the grid in row-major order, yielding the location and value of
+    /// each cell along with the values of its orthogonal neighbours that are within the grid.
+    pub fn iter_with_neighbours(&self) -> impl Iterator<Item = (Point2D, &T, Vec<&T>)> {
+        (0..self.height).flat_map(move |y| {
+            (0..self.width).map(move |x| {
+                let point = Point2D::new(x as i64, y as i64);
+                let neighbours = point
+                    .get_adjacent_points()
+                    .iter()
+                    .filter_map(|p| self.get(p))
+                    .collect::<Vec<&T>>();
+                (point, &self.cells[y * self.width + x], neighbours)
+            })
+        })
+    }
+
+    /// Conducts a breadth-first search of the grid from the start point, moving up, down, left and
+    /// right. A move is only allowed if the passable function returns true when given the values of
+    /// the cell being moved from and the cell being moved to. Returned value is the minimum number
+    /// of steps needed to reach each reachable point in the grid.
+    pub fn bfs_from(
+        &self,
+        start: Point2D,
+        passable: impl Fn(&T, &T) -> bool,
+    ) -> HashMap<Point2D, u64> {
+        let mut distances: HashMap<Point2D, u64> = HashMap::new();
+        if !self.contains_point(&start) {
+            return distances;
+        }
+        let mut visit_queue: VecDeque<Point2D> = VecDeque::from([start]);
+        distances.insert(start, 0);
+        while let Some(current) = visit_queue.pop_front() {
+            let steps = distances[&current];
+            let from = self.get(&current).unwrap();
+            for next in current.get_adjacent_points() {
+                if distances.contains_key(&next) {
+                    continue;
+                }
+                if let Some(to) = self.get(&next) {
+                    if passable(from, to) {
+                        distances.insert(next, steps + 1);
+                        visit_queue.push_back(next);
+                    }
+                }
+            }
+        }
+        distances
+    }
+
+    /// Renders the grid to a string, with each cell converted to a character by the given function
+    /// and each row terminated by a newline.
+    pub fn render(&self, cell_char: impl Fn(&T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for row in self.cells.chunks(self.width.max(1)).take(self.height) {
+            output.extend(row.iter().map(&cell_char));
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Gets the index of the given point within the grid cells.
+    fn index_of(&self, point: &Point2D) -> Option<usize> {
+        if !self.contains_point(point) {
+            return None;
+        }
+        Some(point.y() as usize * self.width + point.x() as usize)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Creates the small 3x2 grid used by the tests.
+    fn sample_grid() -> Grid<u64> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    /// Tests that rotating a grid clockwise four times returns the original grid.
+    #[test]
+    fn test_grid_rotate_cw_90_four_times() {
+        let grid = sample_grid();
+        let rotated = grid
+            .rotate_cw_90()
+            .rotate_cw_90()
+            .rotate_cw_90()
+            .rotate_cw_90();
+        assert_eq!(grid, rotated);
+    }
+
+    /// Tests that rotating a grid counter-clockwise four times returns the original grid.
+    #[test]
+    fn test_grid_rotate_ccw_90_four_times() {
+        let grid = sample_grid();
+        let rotated = grid
+            .rotate_ccw_90()
+            .rotate_ccw_90()
+            .rotate_ccw_90()
+            .rotate_ccw_90();
+        assert_eq!(grid, rotated);
+    }
+
+    /// Tests that a single clockwise rotation places the cells in the expected locations.
+    #[test]
+    fn test_grid_rotate_cw_90_once() {
+        let rotated = sample_grid().rotate_cw_90();
+        let expected = Grid::from_rows(vec![vec![4, 1], vec![5, 2], vec![6, 3]]);
+        assert_eq!(expected, rotated);
+        assert_eq!(sample_grid(), rotated.rotate_ccw_90());
+    }
+
+    /// Tests that transposing a grid swaps its dimensions and the cell coordinates.
+    #[test]
+    fn test_grid_transpose() {
+        let grid = sample_grid();
+        let transposed = grid.transpose();
+        assert_eq!(2, transposed.width());
+        assert_eq!(3, transposed.height());
+        assert_eq!(Some(&6), transposed.get(&Point2D::new(1, 2)));
+        assert_eq!(grid, transposed.transpose());
+    }
+
+    /// Tests the horizontal and vertical flips of a grid.
+    #[test]
+    fn test_grid_flips() {
+        let grid = sample_grid();
+        let expected_h = Grid::from_rows(vec![vec![3, 2, 1], vec![6, 5, 4]]);
+        let expected_v = Grid::from_rows(vec![vec![4, 5, 6], vec![1, 2, 3]]);
+        assert_eq!(expected_h, grid.flip_horizontal());
+        assert_eq!(expected_v, grid.flip_vertical());
+    }
+
+    /// Tests that a sparse point map is converted to a dense grid, with the default value used to
+    /// fill in the gaps.
+    #[test]
+    fn test_grid_from_points() {
+        let mut map: HashMap<Point2D, char> = HashMap::new();
+        map.insert(Point2D::new(-1, 2), '#');
+        map.insert(Point2D::new(2, 3), '#');
+        map.insert(Point2D::new(0, 4), '#');
+        let grid = Grid::from_points(&map, '.');
+        assert_eq!(4, grid.width());
+        assert_eq!(3, grid.height());
+        let expected = Grid::from_rows(vec![
+            vec!['#', '.', '.', '.'],
+            vec!['.', '.', '.', '#'],
+            vec!['.', '#', '.', '.'],
+        ]);
+        assert_eq!(expected, grid);
+    }
+
+    /// Tests that the difference between two grids gives the points of the changed cells.
+    #[test]
+    fn test_grid_diff() {
+        let before = sample_grid();
+        let mut after = sample_grid();
+        after.set(&Point2D::new(0, 0), 7);
+        after.set(&Point2D::new(2, 1), 8);
+        let expected = HashSet::from([Point2D::new(0, 0), Point2D::new(2, 1)]);
+        assert_eq!(expected, before.diff(&after));
+        assert!(before.diff(&sample_grid()).is_empty());
+    }
+
+    /// Tests counting the matching orthogonal neighbours at interior and edge cells of a grid.
+    #[test]
+    fn test_grid_count_orthogonal_matching() {
+        let grid = Grid::from_rows(vec![
+            vec!['#', '#', '.'],
+            vec!['#', '.', '#'],
+            vec!['.', '#', '#'],
+        ]);
+        let is_elf = |c: &char| *c == '#';
+        assert_eq!(
+            4,
+            grid.count_orthogonal_matching(&Point2D::new(1, 1), is_elf)
+        );
+        assert_eq!(
+            1,
+            grid.count_orthogonal_matching(&Point2D::new(1, 0), is_elf)
+        );
+        assert_eq!(
+            2,
+            grid.count_orthogonal_matching(&Point2D::new(0, 0), |c| *c == '#')
+        );
+        assert_eq!(
+            0,
+            grid.count_orthogonal_matching(&Point2D::new(2, 0), |c| *c == '.')
+        );
+    }
+
+    /// Tests counting the matching surrounding cells at corner, edge and interior cells of a grid.
+    #[test]
+    fn test_grid_count_surrounding_matching() {
+        let grid = Grid::new(3, 3, '#');
+        let is_elf = |c: &char| *c == '#';
+        assert_eq!(
+            3,
+            grid.count_surrounding_matching(&Point2D::new(0, 0), is_elf)
+        );
+        assert_eq!(
+            5,
+            grid.count_surrounding_matching(&Point2D::new(1, 0), is_elf)
+        );
+        assert_eq!(
+            8,
+            grid.count_surrounding_matching(&Point2D::new(1, 1), is_elf)
+        );
+        let mut grid = grid;
+        grid.set(&Point2D::new(2, 2), '.');
+        assert_eq!(
+            7,
+            grid.count_surrounding_matching(&Point2D::new(1, 1), is_elf)
+        );
+    }
+
+    /// Tests the breadth-first search using the elevation rule from AOC 2022 Day 12 against the
+    /// example heightmap, where the end point is reached in 31 steps.
+    #[test]
+    fn test_grid_bfs_from_day12_example() {
+        let rows = std::fs::read_to_string("./input/test/day12_t001.txt")
+            .unwrap()
+            .lines()
+            .map(|line| line.chars().collect::<Vec<char>>())
+            .collect::<Vec<Vec<char>>>();
+        let grid = Grid::from_rows(rows);
+        let elevation = |c: &char| match c {
+            'S' => 'a' as i64,
+            'E' => 'z' as i64,
+            _ => *c as i64,
+        };
+        let distances = grid.bfs_from(Point2D::new(0, 0), |from, to| {
+            elevation(to) - elevation(from) <= 1
+        });
+        assert_eq!(Some(&0), distances.get(&Point2D::new(0, 0)));
+        assert_eq!(Some(&31), distances.get(&Point2D::new(5, 2)));
+        assert_eq!(40, distances.len());
+    }
+
+    /// Tests rendering a grid to a string.
+    #[test]
+    fn test_grid_render() {
+        let rendered = sample_grid().render(|v| if v % 2 == 0 { '#' } else { '.' });
+        assert_eq!(".#.\n#.#\n", rendered);
+        assert_eq!("", Grid::new(0, 0, 0).render(|_| '#'));
+    }
+
+    /// Tests that iterating with neighbours yields two neighbours for corner cells, three for edge
+    /// cells and four for interior cells.
+    #[test]
+    fn test_grid_iter_with_neighbours() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let cells = grid.iter_with_neighbours().collect::<Vec<_>>();
+        assert_eq!(9, cells.len());
+        for (point, value, neighbours) in &cells {
+            let expected = match (point.x(), point.y()) {
+                (0 | 2, 0 | 2) => 2,
+                (1, 1) => 4,
+                _ => 3,
+            };
+            assert_eq!(expected, neighbours.len(), "point {:?}", point);
+            assert_eq!(grid.get(point), Some(*value));
+        }
+        let (_, centre, mut neighbours) = cells[4].clone();
+        neighbours.sort();
+        assert_eq!(5, *centre);
+        assert_eq!(vec![&2, &4, &6, &8], neighbours);
+        assert_eq!(0, Grid::new(0, 0, 0).iter_with_neighbours().count());
+    }
+
+    /// Tests extracting rows and columns from the grid, including those outside of the grid.
+    #[test]
+    fn test_grid_row_and_column() {
+        let grid = sample_grid();
+        assert_eq!(vec![&4, &5, &6], grid.row(1));
+        assert_eq!(vec![&2, &5], grid.column(1));
+        assert_eq!(vec![&3, &6], grid.column(2));
+        assert!(grid.row(2).is_empty());
+        assert!(grid.row(-1).is_empty());
+        assert!(grid.column(3).is_empty());
+        assert!(grid.column(-1).is_empty());
+    }
+
+    /// Tests that a ray from an edge cell yields the rest of its row or column in order, and that a
+    /// ray pointing out of the grid yields nothing.
+    #[test]
+    fn test_grid_ray_from_edge() {
+        let grid = sample_grid();
+        let east = grid
+            .ray(&Point2D::new(0, 1), CardinalDirection::East)
+            .collect::<Vec<(Point2D, &u64)>>();
+        assert_eq!(
+            vec![(Point2D::new(1, 1), &5), (Point2D::new(2, 1), &6)],
+            east
+        );
+        let south = grid
+            .ray(&Point2D::new(2, 0), CardinalDirection::South)
+            .map(|(_, value)| *value)
+            .collect::<Vec<u64>>();
+        assert_eq!(vec![6], south);
+        let west = grid
+            .ray(&Point2D::new(2, 0), CardinalDirection::West)
+            .map(|(_, value)| *value)
+            .collect::<Vec<u64>>();
+        assert_eq!(vec![2, 1], west);
+        assert_eq!(
+            0,
+            grid.ray(&Point2D::new(1, 0), CardinalDirection::North)
+                .count()
+        );
+    }
+}
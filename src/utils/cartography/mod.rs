@@ -1,13 +1,27 @@
+mod automaton;
+mod axialhex;
 mod cardinaldirection;
 mod compassdirection;
+mod cubes;
+mod floodfill;
+mod grid;
 mod minmax2d;
 mod minmax3d;
+mod orientation;
 mod point2d;
 mod point3d;
+mod pointmap;
 
+pub use self::automaton::step_automaton;
+pub use self::axialhex::AxialHex;
 pub use self::cardinaldirection::CardinalDirection;
 pub use self::compassdirection::CompassDirection;
+pub use self::cubes::{exterior_surface_area, surface_area};
+pub use self::floodfill::{flood_fill, flood_fill_3d, flood_fill_with_connectivity, Connectivity};
+pub use self::grid::Grid;
 pub use self::minmax2d::MinMax2D;
 pub use self::minmax3d::MinMax3D;
+pub use self::orientation::{is_collinear, is_on_segment, turn_orientation, Orientation};
 pub use self::point2d::Point2D;
 pub use self::point3d::Point3D;
+pub use self::pointmap::{diff_point_maps, dimensions, fold_values, sorted_points, sum_values};
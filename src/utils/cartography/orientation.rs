@@ -0,0 +1,93 @@
+use super::Point2D;
+
+/// Represents the direction of the turn made when travelling through three consecutive points.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Orientation {
+    Left,
+    Right,
+    Straight,
+}
+
+/// Determines the direction of the turn made when travelling from point a to point b and then on to
+/// point c, using the sign of the cross product of (b-a) and (c-b). Points are taken to use the
+/// y-down convention (y-values increase moving south), so a negative cross product is a left turn.
+pub fn turn_orientation(a: &Point2D, b: &Point2D, c: &Point2D) -> Orientation {
+    let (dx1, dy1) = (b.x() - a.x(), b.y() - a.y());
+    let (dx2, dy2) = (c.x() - b.x(), c.y() - b.y());
+    let cross = dx1 * dy2 - dy1 * dx2;
+    match cross.signum() {
+        -1 => Orientation::Left,
+        1 => Orientation::Right,
+        _ => Orientation::Straight,
+    }
+}
+
+/// Checks if the three points lie on a single straight line (the cross product is zero).
+pub fn is_collinear(a: &Point2D, b: &Point2D, c: &Point2D) -> bool {
+    turn_orientation(a, b, c) == Orientation::Straight
+}
+
+/// Checks if point p lies on the line segment (inclusive) between points a and b. The point must be
+/// collinear with the segment end points and within the bounding box of the segment.
+pub fn is_on_segment(p: &Point2D, a: &Point2D, b: &Point2D) -> bool {
+    is_collinear(a, b, p)
+        && p.x() >= a.x().min(b.x())
+        && p.x() <= a.x().max(b.x())
+        && p.y() >= a.y().min(b.y())
+        && p.y() <= a.y().max(b.y())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that heading east then turning north is a left turn.
+    #[test]
+    fn test_turn_orientation_left() {
+        let a = Point2D::new(0, 0);
+        let b = Point2D::new(2, 0);
+        let c = Point2D::new(2, -3);
+        assert_eq!(Orientation::Left, turn_orientation(&a, &b, &c));
+    }
+
+    /// Tests that heading east then turning south is a right turn.
+    #[test]
+    fn test_turn_orientation_right() {
+        let a = Point2D::new(0, 0);
+        let b = Point2D::new(2, 0);
+        let c = Point2D::new(3, 4);
+        assert_eq!(Orientation::Right, turn_orientation(&a, &b, &c));
+    }
+
+    /// Tests that three collinear points do not make a turn.
+    #[test]
+    fn test_turn_orientation_straight() {
+        let a = Point2D::new(-1, -1);
+        let b = Point2D::new(1, 1);
+        let c = Point2D::new(4, 4);
+        assert_eq!(Orientation::Straight, turn_orientation(&a, &b, &c));
+    }
+
+    /// Tests the collinearity check for collinear and non-collinear points.
+    #[test]
+    fn test_is_collinear() {
+        let a = Point2D::new(0, 0);
+        let b = Point2D::new(2, 1);
+        assert!(is_collinear(&a, &b, &Point2D::new(6, 3)));
+        assert!(is_collinear(&a, &b, &Point2D::new(-4, -2)));
+        assert!(!is_collinear(&a, &b, &Point2D::new(6, 4)));
+    }
+
+    /// Tests points that are on a segment, collinear but outside of the segment, and not collinear.
+    #[test]
+    fn test_is_on_segment() {
+        let a = Point2D::new(0, 0);
+        let b = Point2D::new(4, 2);
+        assert!(is_on_segment(&Point2D::new(2, 1), &a, &b));
+        assert!(is_on_segment(&a, &a, &b));
+        assert!(is_on_segment(&Point2D::new(2, 1), &b, &a));
+        assert!(!is_on_segment(&Point2D::new(6, 3), &a, &b));
+        assert!(!is_on_segment(&Point2D::new(-2, -1), &a, &b));
+        assert!(!is_on_segment(&Point2D::new(2, 2), &a, &b));
+    }
+}
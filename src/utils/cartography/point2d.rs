@@ -1,6 +1,14 @@
-use super::CompassDirection;
+use std::cmp::Ordering;
+
+use super::{CardinalDirection, CompassDirection};
 
 /// Represents a single point in two-dimensional Euclidean space.
+///
+/// Points parsed from puzzle input use the y-down convention, with y-values increasing moving
+/// south (down the rows of the input). The direction-based methods (step(), step_n(),
+/// check_move_in_direction()) and angle_to() assume this convention. The remaining methods do not
+/// depend on the direction of the y-axis. Use flip_y() to convert a point to the y-up (maths)
+/// convention and back again.
 #[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
 pub struct Point2D {
     x: i64,
@@ -13,6 +21,15 @@ impl Point2D {
         Self { x, y }
     }
 
+    /// Returns an iterator over every point within the rectangle (inclusive) with the given
+    /// opposite corners, in row-major order (y-value then x-value). The corners can be given in any
+    /// order.
+    pub fn rect_points(corner_a: &Point2D, corner_b: &Point2D) -> impl Iterator<Item = Point2D> {
+        let (min_x, max_x) = (corner_a.x.min(corner_b.x), corner_a.x.max(corner_b.x));
+        let (min_y, max_y) = (corner_a.y.min(corner_b.y), corner_a.y.max(corner_b.y));
+        (min_y..=max_y).flat_map(move |y| (min_x..=max_x).map(move |x| Point2D::new(x, y)))
+    }
+
     /// Gets the value of the x-coordinate.
     pub fn x(&self) -> i64 {
         self.x
@@ -44,13 +61,36 @@ impl Point2D {
         self.y += dy;
     }
 
+    /// Consumes the point and returns it moved by the specified amount in the x- and y-directions.
+    /// Allows moves to be chained, such as `p.moved(1, 0).moved(0, 1)`.
+    pub fn moved(self, dx: i64, dy: i64) -> Point2D {
+        Point2D::new(self.x + dx, self.y + dy)
+    }
+
     /// Returns the Point2D after the current point is moved by the specified x- and y-deltas.
     pub fn peek_move_point(&self, dx: i64, dy: i64) -> Point2D {
         Point2D::new(self.x + dx, self.y + dy)
     }
 
-    /// Gets the eight surrounding points from the current location. Panics if integer overflow or
-    /// underflow would occur.
+    /// Returns the Point2D after the current point is moved by the specified x- and y-deltas, or
+    /// None if either coordinate would overflow or underflow. This is the checked counterpart of
+    /// peek_move_point() for points near the limits of the i64 range.
+    pub fn checked_add(&self, dx: i64, dy: i64) -> Option<Point2D> {
+        Some(Point2D::new(
+            self.x.checked_add(dx)?,
+            self.y.checked_add(dy)?,
+        ))
+    }
+
+    /// Returns the point with each coordinate clamped into the inclusive rectangle bounded by the
+    /// given minimum and maximum corners.
+    pub fn clamp_to(&self, min: &Point2D, max: &Point2D) -> Point2D {
+        Point2D::new(self.x.clamp(min.x, max.x), self.y.clamp(min.y, max.y))
+    }
+
+    /// Gets the eight surrounding points from the current location. The coordinates are not checked
+    /// for overflow, so this panics in debug builds (and wraps around in release builds) if the
+    /// point is at the limit of the i64 range - use checked_surrounding_points() in that case.
     pub fn get_surrounding_points(&self) -> Vec<Point2D> {
         vec![
             Point2D::new(self.x, self.y - 1),     // up
@@ -64,8 +104,34 @@ impl Point2D {
         ]
     }
 
+    /// Gets the surrounding points from the current location, in the same order as
+    /// get_surrounding_points() but skipping any neighbours whose coordinates would overflow or
+    /// underflow.
+    pub fn checked_surrounding_points(&self) -> Vec<Point2D> {
+        [
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+        ]
+        .iter()
+        .filter_map(|(dx, dy)| {
+            Some(Point2D::new(
+                self.x.checked_add(*dx)?,
+                self.y.checked_add(*dy)?,
+            ))
+        })
+        .collect::<Vec<Point2D>>()
+    }
+
     /// Gets the four points adjacent to the current location (excluding diagonals) - up, down,
-    /// left and right. Panics if integer overflow or underflow would occur.
+    /// left and right. The coordinates are not checked for overflow, so this panics in debug builds
+    /// (and wraps around in release builds) if the point is at the limit of the i64 range - use
+    /// checked_add() in that case.
     pub fn get_adjacent_points(&self) -> Vec<Point2D> {
         vec![
             Point2D::new(self.x, self.y - 1), // up
@@ -75,12 +141,73 @@ impl Point2D {
         ]
     }
 
-    /// Calculates the Manhattan distance between the current point and the other point.
+    /// Gets the points adjacent to the current location (excluding diagonals) for which the in-map
+    /// function returns true, in the same order as get_adjacent_points().
+    pub fn get_orthogonal_points_filtered(
+        &self,
+        in_map: impl Fn(&Point2D) -> bool,
+    ) -> Vec<Point2D> {
+        self.get_adjacent_points()
+            .into_iter()
+            .filter(|point| in_map(point))
+            .collect::<Vec<Point2D>>()
+    }
+
+    /// Calculates the Manhattan distance between the current point and the other point. The
+    /// difference along each axis is calculated without overflow for any pair of coordinates, with
+    /// the total saturating at u64::MAX if it cannot be represented.
     pub fn calculate_manhattan_distance(&self, other: &Point2D) -> u64 {
-        (self.x - other.x).unsigned_abs() + (self.y - other.y).unsigned_abs()
+        self.x
+            .abs_diff(other.x)
+            .saturating_add(self.y.abs_diff(other.y))
     }
 
-    /// Gets the point in the given direction from the current point.
+    /// Gets the candidate point with the minimum Manhattan distance from the current point. Ties are
+    /// broken by taking the point that comes first in row-major order (lowest y-value, then lowest
+    /// x-value), so the result does not depend on the order of the candidates. Returns None if there
+    /// are no candidates.
+    pub fn nearest_point(&self, candidates: &[Point2D]) -> Option<Point2D> {
+        candidates
+            .iter()
+            .min_by_key(|p| (self.calculate_manhattan_distance(p), **p))
+            .copied()
+    }
+
+    /// Calculates the angle (in radians) of the vector from the current point to the other point,
+    /// measured from the positive x-axis using atan2 on the raw coordinate deltas. The result is in
+    /// the range (-pi, pi]. As y-values increase moving south (y-down convention), angles increase
+    /// clockwise: east is 0, south is pi/2, west is pi and north is -pi/2.
+    pub fn angle_to(&self, other: &Point2D) -> f64 {
+        let dx = (other.x - self.x) as f64;
+        let dy = (other.y - self.y) as f64;
+        dy.atan2(dx)
+    }
+
+    /// Converts the point between the y-down and y-up conventions for a grid with the given height,
+    /// so that row 0 of a y-down grid becomes row (height - 1) of the y-up grid and vice versa. The
+    /// conversion is its own inverse. The x-value is unchanged.
+    pub fn flip_y(&self, height: i64) -> Point2D {
+        Point2D::new(self.x, height - 1 - self.y)
+    }
+
+    /// Gets the point one step from the current point in the given cardinal direction. North is
+    /// towards decreasing y-values (y-down convention).
+    pub fn step(&self, dirn: CardinalDirection) -> Point2D {
+        self.step_n(dirn, 1)
+    }
+
+    /// Gets the point n steps from the current point in the given cardinal direction.
+    pub fn step_n(&self, dirn: CardinalDirection, n: i64) -> Point2D {
+        match dirn {
+            CardinalDirection::North => self.peek_move_point(0, -n),
+            CardinalDirection::East => self.peek_move_point(n, 0),
+            CardinalDirection::South => self.peek_move_point(0, n),
+            CardinalDirection::West => self.peek_move_point(-n, 0),
+        }
+    }
+
+    /// Gets the point in the given direction from the current point. North is towards decreasing
+    /// y-values (y-down convention).
     pub fn check_move_in_direction(&self, dirn: CompassDirection) -> Point2D {
         match dirn {
             CompassDirection::North => self.peek_move_point(0, -1),
@@ -94,3 +221,221 @@ impl Point2D {
         }
     }
 }
+
+/// Points are ordered in row-major order - by y-value first, then by x-value.
+impl Ord for Point2D {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.y.cmp(&other.y).then(self.x.cmp(&other.x))
+    }
+}
+
+impl PartialOrd for Point2D {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use super::*;
+
+    /// Tests that the points in a 3x2 rectangle are yielded in row-major order, regardless of the
+    /// order the corners are given in.
+    #[test]
+    fn test_point2d_rect_points() {
+        let expected = vec![
+            Point2D::new(1, 4),
+            Point2D::new(2, 4),
+            Point2D::new(3, 4),
+            Point2D::new(1, 5),
+            Point2D::new(2, 5),
+            Point2D::new(3, 5),
+        ];
+        let points = Point2D::rect_points(&Point2D::new(1, 4), &Point2D::new(3, 5));
+        assert_eq!(expected, points.collect::<Vec<Point2D>>());
+        let points = Point2D::rect_points(&Point2D::new(3, 4), &Point2D::new(1, 5));
+        assert_eq!(expected, points.collect::<Vec<Point2D>>());
+    }
+
+    /// Tests that chained moves give the same result as moving the point in place.
+    #[test]
+    fn test_point2d_moved_chain() {
+        let point = Point2D::new(2, -3).moved(1, 0).moved(0, 1).moved(-5, 4);
+        assert_eq!(Point2D::new(-2, 2), point);
+        let mut in_place = Point2D::new(2, -3);
+        in_place.move_point(-4, 5);
+        assert_eq!(in_place, point);
+    }
+
+    /// Tests clamping a point that is out of range on one axis.
+    #[test]
+    fn test_point2d_clamp_to_outside() {
+        let min = Point2D::new(0, 0);
+        let max = Point2D::new(9, 4);
+        assert_eq!(Point2D::new(9, 2), Point2D::new(15, 2).clamp_to(&min, &max));
+        assert_eq!(Point2D::new(3, 0), Point2D::new(3, -7).clamp_to(&min, &max));
+    }
+
+    /// Tests that clamping a point already inside the rectangle leaves it unchanged.
+    #[test]
+    fn test_point2d_clamp_to_inside() {
+        let min = Point2D::new(0, 0);
+        let max = Point2D::new(9, 4);
+        assert_eq!(Point2D::new(5, 4), Point2D::new(5, 4).clamp_to(&min, &max));
+    }
+
+    /// Tests the angles to points in each of the four cardinal directions.
+    #[test]
+    fn test_point2d_angle_to_cardinal_directions() {
+        let origin = Point2D::new(3, 3);
+        assert_eq!(0.0, origin.angle_to(&Point2D::new(8, 3))); // east
+        assert_eq!(FRAC_PI_2, origin.angle_to(&Point2D::new(3, 5))); // south
+        assert_eq!(PI, origin.angle_to(&Point2D::new(-1, 3))); // west
+        assert_eq!(-FRAC_PI_2, origin.angle_to(&Point2D::new(3, 0))); // north
+    }
+
+    /// Tests that points are ordered by y-value first, then by x-value.
+    #[test]
+    fn test_point2d_ord_row_major() {
+        assert!(Point2D::new(5, 0) < Point2D::new(0, 1));
+        assert!(Point2D::new(0, 1) < Point2D::new(1, 1));
+        assert_eq!(Ordering::Equal, Point2D::new(2, 2).cmp(&Point2D::new(2, 2)));
+    }
+
+    /// Tests that the checked surrounding points skip neighbours outside of the i64 range.
+    #[test]
+    fn test_checked_surrounding_points_at_limits() {
+        let corner = Point2D::new(i64::MAX, i64::MAX);
+        let expected = vec![
+            Point2D::new(i64::MAX, i64::MAX - 1),
+            Point2D::new(i64::MAX - 1, i64::MAX),
+            Point2D::new(i64::MAX - 1, i64::MAX - 1),
+        ];
+        assert_eq!(expected, corner.checked_surrounding_points());
+        assert_eq!(
+            3,
+            Point2D::new(i64::MIN, i64::MIN)
+                .checked_surrounding_points()
+                .len()
+        );
+        let origin = Point2D::new(0, 0);
+        assert_eq!(
+            origin.get_surrounding_points(),
+            origin.checked_surrounding_points()
+        );
+    }
+
+    /// Tests the Manhattan distance between points with extreme coordinates.
+    #[test]
+    fn test_calculate_manhattan_distance_extremes() {
+        let min = Point2D::new(i64::MIN, 0);
+        let max = Point2D::new(i64::MAX, 0);
+        assert_eq!(u64::MAX, min.calculate_manhattan_distance(&max));
+        assert_eq!(u64::MAX, max.calculate_manhattan_distance(&min));
+        let a = Point2D::new(i64::MIN, i64::MAX);
+        let b = Point2D::new(-1, 1);
+        let expected = (i64::MAX as u64) + (i64::MAX as u64 - 1);
+        assert_eq!(expected, a.calculate_manhattan_distance(&b));
+        let corner = Point2D::new(i64::MAX, i64::MAX);
+        assert_eq!(
+            u64::MAX,
+            corner.calculate_manhattan_distance(&Point2D::new(i64::MIN, i64::MIN))
+        );
+    }
+
+    /// Tests stepping from the origin in the cardinal directions.
+    #[test]
+    fn test_step() {
+        let origin = Point2D::new(0, 0);
+        assert_eq!(Point2D::new(0, -1), origin.step(CardinalDirection::North));
+        assert_eq!(Point2D::new(1, 0), origin.step(CardinalDirection::East));
+        assert_eq!(
+            Point2D::new(0, 3),
+            origin.step_n(CardinalDirection::South, 3)
+        );
+        assert_eq!(
+            Point2D::new(-3, 0),
+            origin.step_n(CardinalDirection::West, 3)
+        );
+        let walked = origin
+            .step(CardinalDirection::West)
+            .step(CardinalDirection::West)
+            .step(CardinalDirection::West);
+        assert_eq!(origin.step_n(CardinalDirection::West, 3), walked);
+    }
+
+    /// Tests finding the nearest candidate point, with ties broken in row-major order.
+    #[test]
+    fn test_nearest_point() {
+        let from = Point2D::new(0, 0);
+        let candidates = [
+            Point2D::new(5, 5),
+            Point2D::new(2, 1),
+            Point2D::new(-1, -3),
+            Point2D::new(1, -2),
+        ];
+        // (2,1) and (1,-2) are both at distance 3, with (1,-2) having the lower y-value
+        assert_eq!(Some(Point2D::new(1, -2)), from.nearest_point(&candidates));
+        assert_eq!(
+            Some(Point2D::new(5, 5)),
+            from.nearest_point(&candidates[..1])
+        );
+        assert_eq!(None, from.nearest_point(&[]));
+        let tied_on_row = [Point2D::new(2, 0), Point2D::new(-2, 0)];
+        assert_eq!(Some(Point2D::new(-2, 0)), from.nearest_point(&tied_on_row));
+    }
+
+    /// Tests converting points between the y-down and y-up conventions and back again.
+    #[test]
+    fn test_point2d_flip_y_round_trip() {
+        let height = 5;
+        let top_left = Point2D::new(0, 0);
+        assert_eq!(Point2D::new(0, 4), top_left.flip_y(height));
+        assert_eq!(Point2D::new(3, 2), Point2D::new(3, 2).flip_y(height));
+        for point in Point2D::rect_points(&Point2D::new(-2, -1), &Point2D::new(6, 7)) {
+            assert_eq!(point, point.flip_y(height).flip_y(height));
+        }
+        // Stepping north in y-down is the same as stepping towards increasing y-values in y-up
+        let flipped = Point2D::new(1, 3)
+            .step(CardinalDirection::North)
+            .flip_y(height);
+        assert_eq!(Point2D::new(1, 3).flip_y(height).moved(0, 1), flipped);
+    }
+
+    /// Tests that the filtered orthogonal points exclude cells outside of a rectangle.
+    #[test]
+    fn test_get_orthogonal_points_filtered_rectangle() {
+        let (min, max) = (Point2D::new(0, 0), Point2D::new(3, 2));
+        let in_rect = |p: &Point2D| p.clamp_to(&min, &max) == *p;
+        let corner = Point2D::new(0, 0).get_orthogonal_points_filtered(in_rect);
+        assert_eq!(vec![Point2D::new(1, 0), Point2D::new(0, 1)], corner);
+        let edge = Point2D::new(3, 1).get_orthogonal_points_filtered(in_rect);
+        assert_eq!(
+            vec![Point2D::new(3, 0), Point2D::new(3, 2), Point2D::new(2, 1)],
+            edge
+        );
+        assert_eq!(
+            4,
+            Point2D::new(1, 1)
+                .get_orthogonal_points_filtered(in_rect)
+                .len()
+        );
+        assert!(Point2D::new(9, 9)
+            .get_orthogonal_points_filtered(in_rect)
+            .is_empty());
+    }
+
+    /// Tests the checked addition of deltas to points near the limits of the i64 range.
+    #[test]
+    fn test_point2d_checked_add() {
+        let point = Point2D::new(3, -4);
+        assert_eq!(Some(Point2D::new(5, -5)), point.checked_add(2, -1));
+        let near_max = Point2D::new(i64::MAX - 1, 0);
+        assert_eq!(Some(Point2D::new(i64::MAX, 1)), near_max.checked_add(1, 1));
+        assert_eq!(None, near_max.checked_add(2, 0));
+        assert_eq!(None, Point2D::new(0, i64::MAX).checked_add(0, 1));
+        assert_eq!(None, Point2D::new(i64::MIN, 0).checked_add(-1, 0));
+    }
+}
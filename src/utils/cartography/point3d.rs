@@ -72,4 +72,89 @@ impl Point3D {
             self.peek_move_point(0, 0, 1),  // +dz
         ]
     }
+
+    /// Gets every point within the given Manhattan distance of the current point (inclusive),
+    /// including the current point itself. The points form an octahedron, so there are
+    /// (2r + 1)(2r^2 + 2r + 3) / 3 points for radius r.
+    pub fn points_within_manhattan_3d(&self, radius: u64) -> Vec<Point3D> {
+        let r = radius as i64;
+        let mut points: Vec<Point3D> = vec![];
+        for dx in -r..=r {
+            let rem_y = r - dx.abs();
+            for dy in -rem_y..=rem_y {
+                let rem_z = rem_y - dy.abs();
+                for dz in -rem_z..=rem_z {
+                    points.push(self.peek_move_point(dx, dy, dz));
+                }
+            }
+        }
+        points
+    }
+
+    /// Gets every point at exactly the given Manhattan distance from the current point, forming
+    /// the surface of the octahedron given by points_within_manhattan_3d(). There are 4r^2 + 2
+    /// points for radius r greater than 0, and only the current point for radius 0.
+    pub fn points_at_manhattan_3d(&self, radius: u64) -> Vec<Point3D> {
+        let r = radius as i64;
+        let mut points: Vec<Point3D> = vec![];
+        for dx in -r..=r {
+            let rem_y = r - dx.abs();
+            for dy in -rem_y..=rem_y {
+                let dz = rem_y - dy.abs();
+                points.push(self.peek_move_point(dx, dy, dz));
+                if dz != 0 {
+                    points.push(self.peek_move_point(dx, dy, -dz));
+                }
+            }
+        }
+        points
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    /// Tests that the points within radius 1 are the centre point and its six face neighbours.
+    #[test]
+    fn test_point3d_points_within_manhattan_3d_radius1() {
+        let centre = Point3D::new(1, -2, 3);
+        let points = centre.points_within_manhattan_3d(1);
+        assert_eq!(7, points.len());
+        let mut expected = centre.get_adjacent_points();
+        expected.push(centre);
+        assert_eq!(
+            expected.into_iter().collect::<HashSet<Point3D>>(),
+            points.into_iter().collect::<HashSet<Point3D>>()
+        );
+    }
+
+    /// Tests the number of points within and at each radius against the octahedral number
+    /// formulas, and that every point is distinct and at the correct distance.
+    #[test]
+    fn test_point3d_manhattan_3d_counts() {
+        let centre = Point3D::new(0, 0, 0);
+        for radius in 0..=6u64 {
+            let within = centre.points_within_manhattan_3d(radius);
+            let octahedral = (2 * radius + 1) * (2 * radius * radius + 2 * radius + 3) / 3;
+            assert_eq!(octahedral as usize, within.len());
+            assert_eq!(within.len(), within.iter().collect::<HashSet<_>>().len());
+            assert!(within
+                .iter()
+                .all(|p| p.calculate_manhattan_distance(&centre) <= radius));
+            let surface = centre.points_at_manhattan_3d(radius);
+            let expected = if radius == 0 {
+                1
+            } else {
+                4 * radius * radius + 2
+            };
+            assert_eq!(expected as usize, surface.len());
+            assert_eq!(surface.len(), surface.iter().collect::<HashSet<_>>().len());
+            assert!(surface
+                .iter()
+                .all(|p| p.calculate_manhattan_distance(&centre) == radius));
+        }
+    }
 }
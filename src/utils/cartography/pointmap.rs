@@ -0,0 +1,147 @@
+use std::collections::{HashMap, HashSet};
+
+use super::Point2D;
+
+/// Gets the points whose values differ between the two point maps, including points that are only
+/// present in one of the maps.
+pub fn diff_point_maps<T: PartialEq>(
+    before: &HashMap<Point2D, T>,
+    after: &HashMap<Point2D, T>,
+) -> HashSet<Point2D> {
+    let mut changed: HashSet<Point2D> = HashSet::new();
+    for (point, value) in before {
+        if after.get(point) != Some(value) {
+            changed.insert(*point);
+        }
+    }
+    for point in after.keys() {
+        if !before.contains_key(point) {
+            changed.insert(*point);
+        }
+    }
+    changed
+}
+
+/// Gets the points of the given point map sorted in row-major order (by y-value then x-value), so
+/// the points can be processed in a reproducible order.
+pub fn sorted_points<T>(map: &HashMap<Point2D, T>) -> Vec<Point2D> {
+    let mut points = map.keys().copied().collect::<Vec<Point2D>>();
+    points.sort();
+    points
+}
+
+/// Gets the bounds of the points in the given point map, as the tuple (min_x, min_y, max_x, max_y).
+/// Returns None if the point map is empty.
+pub fn dimensions<T>(map: &HashMap<Point2D, T>) -> Option<(i64, i64, i64, i64)> {
+    let mut points = map.keys();
+    let first = points.next()?;
+    let init = (first.x(), first.y(), first.x(), first.y());
+    Some(points.fold(init, |(min_x, min_y, max_x, max_y), p| {
+        (
+            min_x.min(p.x()),
+            min_y.min(p.y()),
+            max_x.max(p.x()),
+            max_y.max(p.y()),
+        )
+    }))
+}
+
+/// Folds over the values of the given point map, starting from the initial value. The values are
+/// visited in row-major order of their points, so the result is reproducible even for folds that
+/// are sensitive to ordering.
+pub fn fold_values<T, A>(map: &HashMap<Point2D, T>, init: A, f: impl Fn(A, &T) -> A) -> A {
+    sorted_points(map)
+        .iter()
+        .fold(init, |acc, point| f(acc, &map[point]))
+}
+
+/// Calculates the sum of the values of the given point map.
+pub fn sum_values(map: &HashMap<Point2D, i64>) -> i64 {
+    map.values().sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the differences between two point maps with a changed, added and removed point.
+    #[test]
+    fn test_diff_point_maps() {
+        let before = HashMap::from([
+            (Point2D::new(0, 0), 'a'),
+            (Point2D::new(1, 0), 'b'),
+            (Point2D::new(2, 0), 'c'),
+        ]);
+        let after = HashMap::from([
+            (Point2D::new(0, 0), 'a'),
+            (Point2D::new(1, 0), 'x'),
+            (Point2D::new(3, 3), 'c'),
+        ]);
+        let expected = HashSet::from([Point2D::new(1, 0), Point2D::new(2, 0), Point2D::new(3, 3)]);
+        assert_eq!(expected, diff_point_maps(&before, &after));
+        assert!(diff_point_maps(&before, &before).is_empty());
+    }
+
+    /// Tests that the sorted points are in row-major order regardless of insertion order.
+    #[test]
+    fn test_sorted_points() {
+        let points = [
+            Point2D::new(2, 1),
+            Point2D::new(-1, 3),
+            Point2D::new(0, 1),
+            Point2D::new(7, -2),
+        ];
+        let forward = points
+            .iter()
+            .map(|p| (*p, 0))
+            .collect::<HashMap<Point2D, u64>>();
+        let reverse = points
+            .iter()
+            .rev()
+            .map(|p| (*p, 0))
+            .collect::<HashMap<Point2D, u64>>();
+        let expected = vec![
+            Point2D::new(7, -2),
+            Point2D::new(0, 1),
+            Point2D::new(2, 1),
+            Point2D::new(-1, 3),
+        ];
+        assert_eq!(expected, sorted_points(&forward));
+        assert_eq!(expected, sorted_points(&reverse));
+    }
+
+    /// Tests summing the values of a small heightmap, and folding to find the highest point.
+    #[test]
+    fn test_sum_and_fold_values() {
+        let heightmap = HashMap::from([
+            (Point2D::new(0, 0), 1),
+            (Point2D::new(1, 0), 4),
+            (Point2D::new(0, 1), -2),
+            (Point2D::new(1, 1), 9),
+        ]);
+        assert_eq!(12, sum_values(&heightmap));
+        assert_eq!(0, sum_values(&HashMap::new()));
+        let max = fold_values(&heightmap, i64::MIN, |acc, value| acc.max(*value));
+        assert_eq!(9, max);
+        let order = fold_values(&heightmap, vec![], |mut acc, value| {
+            acc.push(*value);
+            acc
+        });
+        assert_eq!(vec![1, 4, -2, 9], order);
+    }
+
+    /// Tests the bounds of a scattered point map, and that an empty map has no bounds.
+    #[test]
+    fn test_dimensions() {
+        let map = HashMap::from([
+            (Point2D::new(3, -2), 'a'),
+            (Point2D::new(-4, 5), 'b'),
+            (Point2D::new(7, 1), 'c'),
+            (Point2D::new(0, 9), 'd'),
+        ]);
+        assert_eq!(Some((-4, -2, 7, 9)), dimensions(&map));
+        let single = HashMap::from([(Point2D::new(2, 3), 0)]);
+        assert_eq!(Some((2, 3, 2, 3)), dimensions(&single));
+        assert_eq!(None, dimensions(&HashMap::<Point2D, u64>::new()));
+    }
+}
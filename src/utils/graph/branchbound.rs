@@ -0,0 +1,89 @@
+/// Conducts an exhaustive depth-first search of the state space from the initial state, returning
+/// the maximum value of any state reached. The expand function gives the successor states of a
+/// state. The bound function gives an upper bound on the value of any state reachable from a state
+/// (including the state itself), and a state is pruned without being expanded if its bound does not
+/// exceed the best value found so far. An explicit stack is used rather than recursion.
+pub fn branch_and_bound<S>(
+    initial: S,
+    expand: impl Fn(&S) -> Vec<S>,
+    value: impl Fn(&S) -> u64,
+    bound: impl Fn(&S) -> u64,
+) -> u64 {
+    let mut best = value(&initial);
+    let mut stack: Vec<S> = vec![initial];
+    while let Some(state) = stack.pop() {
+        if bound(&state) <= best {
+            continue;
+        }
+        best = best.max(value(&state));
+        stack.extend(expand(&state));
+    }
+    best
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+
+    use super::*;
+
+    /// Items for the toy knapsack problem, given as (weight, value) pairs.
+    const ITEMS: [(u64, u64); 6] = [(5, 10), (4, 40), (6, 30), (3, 50), (2, 5), (7, 35)];
+    /// Maximum total weight of the items in the knapsack.
+    const CAPACITY: u64 = 10;
+
+    /// State for the knapsack problem - index of next item to consider, total weight and total
+    /// value of the items taken so far.
+    type Knapsack = (usize, u64, u64);
+
+    /// Gets the states after skipping or taking the next item (if it fits in the knapsack).
+    fn expand(state: &Knapsack) -> Vec<Knapsack> {
+        let (index, weight, value) = *state;
+        if index >= ITEMS.len() {
+            return vec![];
+        }
+        let (item_weight, item_value) = ITEMS[index];
+        let mut next = vec![(index + 1, weight, value)];
+        if weight + item_weight <= CAPACITY {
+            next.push((index + 1, weight + item_weight, value + item_value));
+        }
+        next
+    }
+
+    /// Finds the best value for the knapsack problem by trying every subset of items.
+    fn brute_force() -> u64 {
+        (0..1u64 << ITEMS.len())
+            .filter_map(|mask| {
+                let taken = ITEMS
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| mask & (1 << i) != 0)
+                    .map(|(_, item)| *item);
+                let (weight, value) = taken.fold((0, 0), |acc, (w, v)| (acc.0 + w, acc.1 + v));
+                (weight <= CAPACITY).then_some(value)
+            })
+            .max()
+            .unwrap()
+    }
+
+    /// Tests that the known optimum of the toy knapsack problem is found, and that a tight bound
+    /// prunes states compared to an uninformative bound.
+    #[test]
+    fn test_branch_and_bound_knapsack() {
+        let expansions = Cell::new(0);
+        let counted_expand = |state: &Knapsack| {
+            expansions.set(expansions.get() + 1);
+            expand(state)
+        };
+        // Bound assumes every remaining item can also be taken
+        let remaining_bound =
+            |state: &Knapsack| state.2 + ITEMS[state.0..].iter().map(|(_, v)| v).sum::<u64>();
+        let best = branch_and_bound((0, 0, 0), counted_expand, |s| s.2, remaining_bound);
+        assert_eq!(95, best);
+        assert_eq!(brute_force(), best);
+        let pruned_expansions = expansions.replace(0);
+        let best = branch_and_bound((0, 0, 0), counted_expand, |s| s.2, |_| u64::MAX);
+        assert_eq!(95, best);
+        assert!(pruned_expansions < expansions.get());
+    }
+}
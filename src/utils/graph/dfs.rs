@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Conducts a depth-first traversal of the graph from the start node, calling the visit function
+/// once for each reachable node. The neighbours function gives the nodes connected to a node. An
+/// explicit stack is used rather than recursion, so deep graphs do not overflow the call stack.
+/// Returned value is the set of visited nodes.
+pub fn dfs<N: Hash + Eq + Clone>(
+    start: N,
+    mut visit: impl FnMut(&N),
+    neighbours: impl Fn(&N) -> Vec<N>,
+) -> HashSet<N> {
+    let mut visited: HashSet<N> = HashSet::new();
+    let mut stack: Vec<N> = vec![start];
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+        visit(&node);
+        // Push neighbours in reverse so they are visited in the order they were given
+        for next in neighbours(&node).into_iter().rev() {
+            if !visited.contains(&next) {
+                stack.push(next);
+            }
+        }
+    }
+    visited
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    /// Tests that every node reachable in a small graph (with a cycle) is visited exactly once, in
+    /// depth-first order, and that unreachable nodes are not visited.
+    #[test]
+    fn test_dfs_small_graph() {
+        let edges: HashMap<u64, Vec<u64>> = HashMap::from([
+            (1, vec![2, 3]),
+            (2, vec![4]),
+            (3, vec![4, 1]),
+            (4, vec![2]),
+            (5, vec![1]),
+        ]);
+        let mut order: Vec<u64> = vec![];
+        let visited = dfs(1, |node| order.push(*node), |node| edges[node].clone());
+        assert_eq!(vec![1, 2, 4, 3], order);
+        assert_eq!(HashSet::from([1, 2, 3, 4]), visited);
+    }
+
+    /// Tests that a very deep graph is traversed without overflowing the stack.
+    #[test]
+    fn test_dfs_deep_chain() {
+        let mut count = 0;
+        let visited = dfs(
+            0u64,
+            |_| count += 1,
+            |node| {
+                if *node < 100_000 {
+                    vec![node + 1]
+                } else {
+                    vec![]
+                }
+            },
+        );
+        assert_eq!(100_001, count);
+        assert_eq!(100_001, visited.len());
+    }
+}
@@ -0,0 +1,7 @@
+mod branchbound;
+mod dfs;
+mod reachable;
+
+pub use self::branchbound::branch_and_bound;
+pub use self::dfs::dfs;
+pub use self::reachable::reachable_count;
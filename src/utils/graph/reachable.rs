@@ -0,0 +1,44 @@
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Counts the number of nodes reachable from the start node (including the start node itself)
+/// using a breadth-first search. The neighbours function gives the nodes connected to a node.
+pub fn reachable_count<N: Hash + Eq + Clone>(start: N, neighbours: impl Fn(&N) -> Vec<N>) -> usize {
+    let mut visited: HashSet<N> = HashSet::from([start.clone()]);
+    let mut visit_queue: VecDeque<N> = VecDeque::from([start]);
+    while let Some(node) = visit_queue.pop_front() {
+        for next in neighbours(&node) {
+            if visited.insert(next.clone()) {
+                visit_queue.push_back(next);
+            }
+        }
+    }
+    visited.len()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::utils::cartography::{Grid, Point2D};
+
+    use super::*;
+
+    /// Tests that the cells walled off from the start point are excluded from the count.
+    #[test]
+    fn test_reachable_count_walled_off_region() {
+        let rows = ["..#..", "..#..", "###..", "....."];
+        let grid = Grid::from_rows(
+            rows.iter()
+                .map(|row| row.chars().collect::<Vec<char>>())
+                .collect(),
+        );
+        let open_neighbours = |point: &Point2D| {
+            point
+                .get_adjacent_points()
+                .into_iter()
+                .filter(|p| grid.get(p) == Some(&'.'))
+                .collect::<Vec<Point2D>>()
+        };
+        assert_eq!(4, reachable_count(Point2D::new(0, 0), open_neighbours));
+        assert_eq!(11, reachable_count(Point2D::new(4, 3), open_neighbours));
+    }
+}
@@ -0,0 +1,3 @@
+mod rangeset;
+
+pub use self::rangeset::RangeSet;
@@ -0,0 +1,201 @@
+/// Represents a set of integers stored as sorted, disjoint inclusive ranges. Overlapping or
+/// adjacent ranges are merged when inserted, so the set is always held in its minimal form.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct RangeSet {
+    ranges: Vec<(i64, i64)>,
+}
+
+impl RangeSet {
+    /// Creates a new empty range set.
+    pub fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    /// Gets the inclusive ranges held by the set, sorted in ascending order.
+    pub fn ranges(&self) -> &[(i64, i64)] {
+        &self.ranges
+    }
+
+    /// Checks if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Counts the number of integers covered by the set. The count saturates at u64::MAX, which is
+    /// one less than the true count for a set covering every i64 value.
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().fold(0, |total: u64, (lo, hi)| {
+            total.saturating_add(hi.abs_diff(*lo).saturating_add(1))
+        })
+    }
+
+    /// Checks if the given value is covered by the set.
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.ranges.partition_point(|(_, hi)| *hi < value);
+        self.ranges.get(index).is_some_and(|(lo, _)| *lo <= value)
+    }
+
+    /// Adds the inclusive range lo..=hi to the set, merging it with any ranges it overlaps or is
+    /// adjacent to. Does nothing if lo is greater than hi.
+    pub fn insert(&mut self, lo: i64, hi: i64) {
+        if lo > hi {
+            return;
+        }
+        // Find the ranges that overlap or touch the new range
+        let start = self
+            .ranges
+            .partition_point(|(_, end)| end.saturating_add(1) < lo);
+        let end = self
+            .ranges
+            .partition_point(|(begin, _)| *begin <= hi.saturating_add(1));
+        let mut merged = (lo, hi);
+        if start < end {
+            merged.0 = merged.0.min(self.ranges[start].0);
+            merged.1 = merged.1.max(self.ranges[end - 1].1);
+        }
+        self.ranges.splice(start..end, [merged]);
+    }
+
+    /// Removes the inclusive range lo..=hi from the set. Any range straddling either end of the
+    /// removed range is trimmed, and a range covering the whole of it is split in two. Does
+    /// nothing if lo is greater than hi.
+    pub fn remove(&mut self, lo: i64, hi: i64) {
+        if lo > hi {
+            return;
+        }
+        // Find the ranges that overlap the removed range
+        let start = self.ranges.partition_point(|(_, end)| *end < lo);
+        let end = self.ranges.partition_point(|(begin, _)| *begin <= hi);
+        if start >= end {
+            return;
+        }
+        let mut remaining: Vec<(i64, i64)> = vec![];
+        let (first_lo, _) = self.ranges[start];
+        let (_, last_hi) = self.ranges[end - 1];
+        if first_lo < lo {
+            remaining.push((first_lo, lo - 1));
+        }
+        if last_hi > hi {
+            remaining.push((hi + 1, last_hi));
+        }
+        self.ranges.splice(start..end, remaining);
+    }
+
+    /// Finds the smallest integer within the inclusive search window search_lo..=search_hi that is
+    /// not covered by the set. Only the ranges overlapping the window are visited, so the cost does
+    /// not depend on the size of the ranges or the window.
+    pub fn first_gap(&self, search_lo: i64, search_hi: i64) -> Option<i64> {
+        if search_lo > search_hi {
+            return None;
+        }
+        let start = self.ranges.partition_point(|(_, hi)| *hi < search_lo);
+        let mut candidate = search_lo;
+        for (lo, hi) in &self.ranges[start..] {
+            if *lo > candidate {
+                break;
+            }
+            // Candidate is covered by this range, so try the first value after it
+            candidate = hi.checked_add(1)?;
+        }
+        (candidate <= search_hi).then_some(candidate)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Creates a range set from the given inclusive ranges.
+    fn range_set(ranges: &[(i64, i64)]) -> RangeSet {
+        let mut set = RangeSet::new();
+        for (lo, hi) in ranges {
+            set.insert(*lo, *hi);
+        }
+        set
+    }
+
+    /// Tests that overlapping and adjacent ranges are merged on insertion.
+    #[test]
+    fn test_rangeset_insert_merges() {
+        let set = range_set(&[(12, 12), (-2, 2), (2, 14), (16, 24), (15, 15), (30, 40)]);
+        assert_eq!(&[(-2, 24), (30, 40)], set.ranges());
+        assert_eq!(38, set.len());
+        assert!(set.contains(24));
+        assert!(!set.contains(25));
+        assert!(set.contains(30));
+    }
+
+    /// Tests removing the middle of a range, which splits it in two.
+    #[test]
+    fn test_rangeset_remove_middle() {
+        let mut set = range_set(&[(0, 10)]);
+        set.remove(4, 6);
+        assert_eq!(&[(0, 3), (7, 10)], set.ranges());
+        assert!(!set.contains(5));
+    }
+
+    /// Tests removing an interval overlapping the edges of ranges, which trims them.
+    #[test]
+    fn test_rangeset_remove_edge() {
+        let mut set = range_set(&[(0, 10), (20, 30)]);
+        set.remove(-5, 2);
+        set.remove(8, 22);
+        assert_eq!(&[(3, 7), (23, 30)], set.ranges());
+        set.remove(30, 30);
+        assert_eq!(&[(3, 7), (23, 29)], set.ranges());
+    }
+
+    /// Tests removing the whole of a range, including when the removed interval is larger.
+    #[test]
+    fn test_rangeset_remove_whole() {
+        let mut set = range_set(&[(0, 10), (20, 30), (40, 50)]);
+        set.remove(20, 30);
+        assert_eq!(&[(0, 10), (40, 50)], set.ranges());
+        set.remove(-100, 100);
+        assert!(set.is_empty());
+        set.remove(1, 0);
+        assert_eq!(0, set.len());
+    }
+
+    /// Tests counting the integers in sets covering all or nearly all of the i64 values.
+    #[test]
+    fn test_rangeset_len_full_range() {
+        let set = range_set(&[(i64::MIN, -1), (0, i64::MAX)]);
+        assert_eq!(&[(i64::MIN, i64::MAX)], set.ranges());
+        assert_eq!(u64::MAX, set.len());
+        assert!(set.contains(i64::MIN));
+        assert!(set.contains(i64::MAX));
+        let set = range_set(&[(i64::MIN, -2), (0, i64::MAX)]);
+        assert_eq!(u64::MAX, set.len());
+        let set = range_set(&[(i64::MIN, -2), (1, i64::MAX)]);
+        assert_eq!(u64::MAX - 1, set.len());
+    }
+
+    /// Tests finding the single uncovered value within a large search window.
+    #[test]
+    fn test_rangeset_first_gap_single() {
+        let set = range_set(&[(0, 2_999_999), (3_000_001, 4_000_000)]);
+        assert_eq!(Some(3_000_000), set.first_gap(0, 4_000_000));
+        assert_eq!(Some(-1), set.first_gap(-1, 4_000_000));
+    }
+
+    /// Tests that no gap is found when the search window is fully covered.
+    #[test]
+    fn test_rangeset_first_gap_none() {
+        let set = range_set(&[(-10, 5), (6, 20)]);
+        assert_eq!(None, set.first_gap(0, 20));
+        assert_eq!(None, set.first_gap(10, 5));
+        let set = range_set(&[(0, i64::MAX)]);
+        assert_eq!(None, set.first_gap(0, i64::MAX));
+    }
+
+    /// Tests finding a gap at either boundary of the search window.
+    #[test]
+    fn test_rangeset_first_gap_boundary() {
+        let set = range_set(&[(1, 19)]);
+        assert_eq!(Some(0), set.first_gap(0, 20));
+        assert_eq!(Some(20), set.first_gap(1, 20));
+        assert_eq!(None, set.first_gap(1, 19));
+        assert_eq!(Some(7), RangeSet::new().first_gap(7, 7));
+    }
+}
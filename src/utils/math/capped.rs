@@ -0,0 +1,71 @@
+use std::ops::Add;
+
+/// Represents a count that saturates at a ceiling, so that any amount beyond the ceiling is treated
+/// as equal to the ceiling. This is useful when deduplicating search states, where amounts above
+/// the maximum that could be used make no difference to the outcome.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct Capped {
+    value: u64,
+    ceiling: u64,
+}
+
+impl Capped {
+    /// Creates a new capped count with the given value clamped to the ceiling.
+    pub fn new(value: u64, ceiling: u64) -> Self {
+        Self {
+            value: value.min(ceiling),
+            ceiling,
+        }
+    }
+
+    /// Gets the value of the count, which never exceeds the ceiling.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Gets the ceiling of the count.
+    pub fn ceiling(&self) -> u64 {
+        self.ceiling
+    }
+
+    /// Checks if the count has reached its ceiling.
+    pub fn is_saturated(&self) -> bool {
+        self.value == self.ceiling
+    }
+}
+
+impl Add<u64> for Capped {
+    type Output = Capped;
+
+    fn add(self, rhs: u64) -> Self::Output {
+        Capped::new(self.value.saturating_add(rhs), self.ceiling)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that capping at a ceiling makes different totals above the ceiling compare equal.
+    #[test]
+    fn test_capped_totals_compare_equal() {
+        let ceiling = 24;
+        assert_eq!(Capped::new(30, ceiling), Capped::new(57, ceiling));
+        assert_eq!(Capped::new(24, ceiling), Capped::new(57, ceiling));
+        assert_ne!(Capped::new(23, ceiling), Capped::new(57, ceiling));
+        assert!(Capped::new(57, ceiling).is_saturated());
+        assert_eq!(24, Capped::new(57, ceiling).value());
+    }
+
+    /// Tests that addition saturates at the ceiling, including when it would overflow.
+    #[test]
+    fn test_capped_add() {
+        let count = Capped::new(5, 10) + 3;
+        assert_eq!(8, count.value());
+        assert_eq!(10, (count + 3).value());
+        assert_eq!(
+            Capped::new(u64::MAX, u64::MAX),
+            Capped::new(5, u64::MAX) + u64::MAX
+        );
+    }
+}
@@ -0,0 +1,57 @@
+/// Calculates n factorial. Returns None if the result would overflow a u64.
+pub fn factorial(n: u64) -> Option<u64> {
+    (1..=n).try_fold(1u64, |acc, i| acc.checked_mul(i))
+}
+
+/// Calculates the number of ways of choosing k items from n items without regard to order (the
+/// binomial coefficient). Returns Some(0) if k is greater than n, and None if the result would
+/// overflow a u64.
+pub fn n_choose_k(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 0..k {
+        // Result stays an integer at each step, since it is (n choose i+1) after the division
+        result = result.checked_mul((n - i) as u128)? / (i + 1) as u128;
+    }
+    u64::try_from(result).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that 5 choose 2 is 10.
+    #[test]
+    fn test_n_choose_k_5_choose_2() {
+        assert_eq!(Some(10), n_choose_k(5, 2));
+        assert_eq!(Some(10), n_choose_k(5, 3));
+    }
+
+    /// Tests that choosing zero items (or all items) gives one combination.
+    #[test]
+    fn test_n_choose_k_zero() {
+        assert_eq!(Some(1), n_choose_k(0, 0));
+        assert_eq!(Some(1), n_choose_k(7, 0));
+        assert_eq!(Some(1), n_choose_k(7, 7));
+        assert_eq!(Some(0), n_choose_k(3, 4));
+    }
+
+    /// Tests large binomial coefficients near and beyond the limit of a u64.
+    #[test]
+    fn test_n_choose_k_large() {
+        assert_eq!(Some(14226520737620288370), n_choose_k(67, 33));
+        assert_eq!(None, n_choose_k(68, 34));
+    }
+
+    /// Tests factorial values and that overflow results in None.
+    #[test]
+    fn test_factorial() {
+        assert_eq!(Some(1), factorial(0));
+        assert_eq!(Some(120), factorial(5));
+        assert_eq!(Some(2432902008176640000), factorial(20));
+        assert_eq!(None, factorial(21));
+    }
+}
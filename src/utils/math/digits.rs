@@ -0,0 +1,51 @@
+/// Gets the decimal digits of the given number, ordered from the most-significant digit to the
+/// least-significant digit. Zero is treated as having a single digit of value 0.
+pub fn digits(n: u64) -> Vec<u8> {
+    let mut output: Vec<u8> = vec![];
+    let mut n = n;
+    loop {
+        output.push((n % 10) as u8);
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    output.reverse();
+    output
+}
+
+/// Gets the number of decimal digits in the given number. Zero is treated as having one digit.
+pub fn num_digits(n: u64) -> u32 {
+    match n.checked_ilog10() {
+        Some(log) => log + 1,
+        None => 1,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the digits of a typical number.
+    #[test]
+    fn test_digits_12345() {
+        assert_eq!(vec![1, 2, 3, 4, 5], digits(12345));
+        assert_eq!(5, num_digits(12345));
+    }
+
+    /// Tests that zero has a single digit of value 0.
+    #[test]
+    fn test_digits_zero() {
+        assert_eq!(vec![0], digits(0));
+        assert_eq!(1, num_digits(0));
+    }
+
+    /// Tests the digits of the largest u64 value.
+    #[test]
+    fn test_digits_u64_max() {
+        let expected = vec![1, 8, 4, 4, 6, 7, 4, 4, 0, 7, 3, 7, 0, 9, 5, 5, 1, 6, 1, 5];
+        assert_eq!(expected, digits(u64::MAX));
+        assert_eq!(20, num_digits(u64::MAX));
+        assert_eq!(19, num_digits(u64::MAX / 10));
+    }
+}
@@ -0,0 +1,141 @@
+use std::ops::{Add, Sub};
+
+use super::Rational;
+
+/// Represents a linear expression a*x + b in a single unknown x, with exact rational coefficients.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct LinearExpr {
+    a: Rational,
+    b: Rational,
+}
+
+impl LinearExpr {
+    /// Creates a new linear expression a*x + b.
+    pub fn new(a: Rational, b: Rational) -> Self {
+        Self { a, b }
+    }
+
+    /// Creates a linear expression with the constant value (a = 0).
+    pub fn constant(value: Rational) -> Self {
+        Self::new(Rational::from_int(0), value)
+    }
+
+    /// Creates a linear expression for the unknown itself (a = 1, b = 0).
+    pub fn variable() -> Self {
+        Self::new(Rational::from_int(1), Rational::from_int(0))
+    }
+
+    /// Gets the coefficient of the unknown.
+    pub fn a(&self) -> Rational {
+        self.a
+    }
+
+    /// Gets the constant term.
+    pub fn b(&self) -> Rational {
+        self.b
+    }
+
+    /// Gets the value of the expression if it does not depend on the unknown (a = 0).
+    pub fn as_constant(&self) -> Option<Rational> {
+        if self.a.is_zero() {
+            Some(self.b)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the expression multiplied by the constant.
+    pub fn mul_const(&self, value: Rational) -> LinearExpr {
+        LinearExpr::new(self.a * value, self.b * value)
+    }
+
+    /// Returns the expression divided by the constant. Returns None if the constant is zero.
+    pub fn div_const(&self, value: Rational) -> Option<LinearExpr> {
+        if value.is_zero() {
+            return None;
+        }
+        Some(LinearExpr::new(self.a / value, self.b / value))
+    }
+
+    /// Solves a*x + b = target for the unknown x. Returns None if the expression does not depend
+    /// on the unknown (a = 0), in which case there is no unique solution.
+    pub fn solve_for(&self, target: Rational) -> Option<Rational> {
+        if self.a.is_zero() {
+            return None;
+        }
+        Some((target - self.b) / self.a)
+    }
+}
+
+impl Add for LinearExpr {
+    type Output = LinearExpr;
+
+    fn add(self, other: LinearExpr) -> LinearExpr {
+        LinearExpr::new(self.a + other.a, self.b + other.b)
+    }
+}
+
+impl Sub for LinearExpr {
+    type Output = LinearExpr;
+
+    fn sub(self, other: LinearExpr) -> LinearExpr {
+        LinearExpr::new(self.a - other.a, self.b - other.b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Creates the linear expression a*x + b with integer coefficients.
+    fn expr(a: i64, b: i64) -> LinearExpr {
+        LinearExpr::new(Rational::from_int(a), Rational::from_int(b))
+    }
+
+    /// Tests adding and subtracting linear expressions.
+    #[test]
+    fn test_linear_expr_add_sub() {
+        assert_eq!(expr(5, 1), expr(2, 3) + expr(3, -2));
+        assert_eq!(expr(-1, 5), expr(2, 3) - expr(3, -2));
+        assert_eq!(
+            Some(Rational::from_int(5)),
+            (expr(2, 3) - expr(2, -2)).as_constant()
+        );
+        assert_eq!(None, expr(2, 3).as_constant());
+    }
+
+    /// Tests multiplying and dividing linear expressions by constants.
+    #[test]
+    fn test_linear_expr_mul_div_const() {
+        let half = Rational::new(1, 2);
+        assert_eq!(expr(4, 6), expr(2, 3).mul_const(Rational::from_int(2)));
+        assert_eq!(Some(expr(4, 6)), expr(2, 3).div_const(half));
+        let divided = expr(2, 3).div_const(Rational::from_int(2)).unwrap();
+        assert_eq!(
+            (Rational::from_int(1), Rational::new(3, 2)),
+            (divided.a(), divided.b())
+        );
+        assert_eq!(None, expr(2, 3).div_const(Rational::from_int(0)));
+    }
+
+    /// Tests solving linear expressions for the unknown.
+    #[test]
+    fn test_linear_expr_solve_for() {
+        assert_eq!(
+            Some(Rational::from_int(4)),
+            expr(3, -2).solve_for(Rational::from_int(10))
+        );
+        assert_eq!(
+            Some(Rational::new(1, 2)),
+            expr(2, 0).solve_for(Rational::from_int(1))
+        );
+        assert_eq!(
+            Some(Rational::from_int(7)),
+            LinearExpr::variable().solve_for(Rational::from_int(7))
+        );
+        assert_eq!(
+            None,
+            LinearExpr::constant(Rational::from_int(3)).solve_for(Rational::from_int(3))
+        );
+    }
+}
@@ -0,0 +1,17 @@
+mod capped;
+mod combinatorics;
+mod digits;
+mod linear;
+mod modular;
+mod primes;
+mod rational;
+mod snafu;
+
+pub use self::capped::Capped;
+pub use self::combinatorics::{factorial, n_choose_k};
+pub use self::digits::{digits, num_digits};
+pub use self::linear::LinearExpr;
+pub use self::modular::{crt, extended_gcd, mod_inverse};
+pub use self::primes::{is_prime, sieve_primes};
+pub use self::rational::Rational;
+pub use self::snafu::{from_snafu, to_snafu};
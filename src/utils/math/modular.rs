@@ -0,0 +1,115 @@
+/// Calculates the greatest common divisor of a and b, along with the Bezout coefficients x and y
+/// such that a*x + b*y = gcd(a, b). Returned value is the tuple (gcd, x, y).
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        return (-old_r, -old_x, -old_y);
+    }
+    (old_r, old_x, old_y)
+}
+
+/// Calculates the modular multiplicative inverse of a modulo m, in the range [0, m). Returns None if
+/// the modulus is not positive or a and m are not coprime (no inverse exists).
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+    let (gcd, x, _) = extended_gcd(a.rem_euclid(m), m);
+    if gcd != 1 {
+        return None;
+    }
+    Some(x.rem_euclid(m))
+}
+
+/// Solves the system of congruences x = r (mod m) given as (r, m) pairs using the Chinese Remainder
+/// Theorem. The moduli do not need to be pairwise coprime. Returned value is the combined
+/// congruence (r, m) with r in the range [0, m), or None if the system is inconsistent or contains a
+/// non-positive modulus.
+pub fn crt(residues: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut combined: (i128, i128) = (0, 1);
+    for &(r, m) in residues {
+        if m <= 0 {
+            return None;
+        }
+        let (r1, m1) = combined;
+        let (r2, m2) = (r as i128, m as i128);
+        // The combined modulus never shrinks, so there is no i64 result once it leaves the i64 range
+        let (gcd, _, _) = extended_gcd(i64::try_from(m1).ok()?, m);
+        let gcd = gcd as i128;
+        // Congruences can only be combined if they agree modulo the gcd of their moduli
+        if (r2 - r1).rem_euclid(gcd) != 0 {
+            return None;
+        }
+        let m2_reduced = m2 / gcd;
+        let inverse = mod_inverse(
+            i64::try_from(m1 / gcd).ok()?,
+            i64::try_from(m2_reduced).ok()?,
+        )? as i128;
+        let k = ((r2 - r1) / gcd * inverse).rem_euclid(m2_reduced);
+        let lcm = m1 * m2_reduced;
+        combined = ((r1 + m1 * k).rem_euclid(lcm), lcm);
+    }
+    Some((
+        i64::try_from(combined.0).ok()?,
+        i64::try_from(combined.1).ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the extended Euclidean algorithm produces valid Bezout coefficients.
+    #[test]
+    fn test_extended_gcd() {
+        let (gcd, x, y) = extended_gcd(240, 46);
+        assert_eq!(2, gcd);
+        assert_eq!(2, 240 * x + 46 * y);
+    }
+
+    /// Tests the modular inverse where one exists and where it does not.
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(Some(7), mod_inverse(-3, 11));
+        assert_eq!(None, mod_inverse(6, 9));
+    }
+
+    /// Tests the classic Chinese Remainder Theorem example.
+    #[test]
+    fn test_crt_classic() {
+        assert_eq!(Some((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+    }
+
+    /// Tests that non-coprime but consistent moduli are combined using their lowest common multiple.
+    #[test]
+    fn test_crt_non_coprime_consistent() {
+        assert_eq!(Some((10, 12)), crt(&[(4, 6), (2, 4)]));
+    }
+
+    /// Tests that an inconsistent system of congruences has no solution.
+    #[test]
+    fn test_crt_inconsistent() {
+        assert_eq!(None, crt(&[(1, 4), (2, 6)]));
+    }
+
+    /// Tests combining congruences with large moduli whose product is close to the limit of the i64
+    /// range, and that there is no solution once the combined modulus exceeds the i64 range.
+    #[test]
+    fn test_crt_large_moduli() {
+        let (m1, m2) = (2_000_000_011, 4_000_000_007);
+        assert_eq!(
+            Some((6_933_333_384_133_333_408, 8_000_000_058_000_000_077)),
+            crt(&[(5, m1), (7, m2)])
+        );
+        assert_eq!(None, crt(&[(5, m1), (7, m2), (1, 3)]));
+    }
+}
@@ -0,0 +1,80 @@
+/// Checks if the given number is prime, using trial division up to the square root of the number.
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    if n < 4 {
+        return true;
+    }
+    if n.is_multiple_of(2) {
+        return false;
+    }
+    let mut divisor = 3;
+    while divisor <= n / divisor {
+        if n.is_multiple_of(divisor) {
+            return false;
+        }
+        divisor += 2;
+    }
+    true
+}
+
+/// Gets all of the prime numbers less than or equal to the given limit, using the Sieve of
+/// Eratosthenes. Primes are returned in ascending order.
+pub fn sieve_primes(limit: u64) -> Vec<u64> {
+    if limit < 2 {
+        return vec![];
+    }
+    let limit = limit as usize;
+    let mut is_composite = vec![false; limit + 1];
+    let mut primes: Vec<u64> = vec![];
+    for n in 2..=limit {
+        if is_composite[n] {
+            continue;
+        }
+        primes.push(n as u64);
+        // Mark the multiples of the prime, starting from its square
+        let mut multiple = n * n;
+        while multiple <= limit {
+            is_composite[multiple] = true;
+            multiple += n;
+        }
+    }
+    primes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the primality check against small primes.
+    #[test]
+    fn test_is_prime_small_primes() {
+        for n in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 7919] {
+            assert!(is_prime(n), "{} should be prime", n);
+        }
+    }
+
+    /// Tests the primality check against composite numbers.
+    #[test]
+    fn test_is_prime_composites() {
+        for n in [4, 6, 9, 15, 25, 49, 91, 7917, 7921] {
+            assert!(!is_prime(n), "{} should not be prime", n);
+        }
+    }
+
+    /// Tests that 0 and 1 are not considered prime.
+    #[test]
+    fn test_is_prime_zero_and_one() {
+        assert!(!is_prime(0));
+        assert!(!is_prime(1));
+    }
+
+    /// Tests that the sieve up to 30 returns the ten primes below 30.
+    #[test]
+    fn test_sieve_primes_30() {
+        let expected = vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
+        assert_eq!(expected, sieve_primes(30));
+        assert!(sieve_primes(1).is_empty());
+    }
+}
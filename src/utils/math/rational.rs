@@ -0,0 +1,177 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Represents an exact rational number. The fraction is always stored in lowest terms with a
+/// positive denominator, so equal values have equal representations.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Rational {
+    num: i64,
+    den: i64,
+}
+
+impl Rational {
+    /// Creates a new rational number reduced to lowest terms. Panics if the denominator is zero or
+    /// the reduced fraction does not fit within i64.
+    pub fn new(num: i64, den: i64) -> Self {
+        Rational::from_i128(num as i128, den as i128)
+    }
+
+    /// Creates a new rational number equal to the given integer.
+    pub fn from_int(value: i64) -> Self {
+        Self { num: value, den: 1 }
+    }
+
+    /// Gets the numerator of the rational number (in lowest terms).
+    pub fn num(&self) -> i64 {
+        self.num
+    }
+
+    /// Gets the denominator of the rational number (in lowest terms). Always positive.
+    pub fn den(&self) -> i64 {
+        self.den
+    }
+
+    /// Checks if the rational number is zero.
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    /// Gets the value of the rational number as an integer, or None if it is not a whole number.
+    pub fn to_integer(&self) -> Option<i64> {
+        if self.den == 1 {
+            Some(self.num)
+        } else {
+            None
+        }
+    }
+
+    /// Creates a rational number from a numerator and denominator held as i128 values, so that
+    /// intermediate results of arithmetic operations do not overflow before being reduced.
+    fn from_i128(num: i128, den: i128) -> Self {
+        if den == 0 {
+            panic!("Rational number cannot have a denominator of zero!");
+        }
+        let divisor = gcd(num, den) * den.signum();
+        Self {
+            num: i64::try_from(num / divisor).expect("Rational numerator overflowed i64!"),
+            den: i64::try_from(den / divisor).expect("Rational denominator overflowed i64!"),
+        }
+    }
+}
+
+/// Calculates the greatest common divisor of a and b (always non-negative).
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        let num = self.num as i128 * other.den as i128 + other.num as i128 * self.den as i128;
+        Rational::from_i128(num, self.den as i128 * other.den as i128)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self + (-other)
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        Rational::from_i128(
+            self.num as i128 * other.num as i128,
+            self.den as i128 * other.den as i128,
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// Divides by the other rational number. Panics if the other rational number is zero.
+    fn div(self, other: Rational) -> Rational {
+        Rational::from_i128(
+            self.num as i128 * other.den as i128,
+            self.den as i128 * other.num as i128,
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Self {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that rational numbers are reduced to lowest terms with a positive denominator.
+    #[test]
+    fn test_rational_reduction() {
+        assert_eq!(Rational::new(1, 2), Rational::new(2, 4));
+        assert_eq!(
+            (1, 2),
+            (Rational::new(2, 4).num(), Rational::new(2, 4).den())
+        );
+        assert_eq!(
+            (-3, 4),
+            (Rational::new(6, -8).num(), Rational::new(6, -8).den())
+        );
+        assert_eq!(
+            (0, 1),
+            (Rational::new(0, -5).num(), Rational::new(0, -5).den())
+        );
+        assert_eq!("-3/4", Rational::new(6, -8).to_string());
+        assert_eq!("7", Rational::new(14, 2).to_string());
+    }
+
+    /// Tests addition, subtraction, multiplication and division of rational numbers.
+    #[test]
+    fn test_rational_arithmetic() {
+        let a = Rational::new(1, 2);
+        let b = Rational::new(1, 3);
+        assert_eq!(Rational::new(5, 6), a + b);
+        assert_eq!(Rational::new(1, 6), a - b);
+        assert_eq!(Rational::new(-1, 6), b - a);
+        assert_eq!(Rational::new(1, 6), a * b);
+        assert_eq!(Rational::new(3, 2), a / b);
+        assert_eq!(Some(1), (a + a).to_integer());
+        assert_eq!(None, a.to_integer());
+        assert!((a - a).is_zero());
+    }
+
+    /// Tests that dividing by zero panics.
+    #[test]
+    #[should_panic]
+    fn test_rational_divide_by_zero() {
+        let _ = Rational::from_int(1) / Rational::from_int(0);
+    }
+}
@@ -0,0 +1,87 @@
+/// Converts the given SNAFU number (balanced base-5) into its decimal value. Each digit is one of
+/// '2', '1', '0', '-' (minus one) or '=' (minus two), with the most-significant digit first. Panics
+/// if the string contains any other character.
+pub fn from_snafu(s: &str) -> i64 {
+    s.chars().fold(0, |acc, c| {
+        let digit = match c {
+            '2' => 2,
+            '1' => 1,
+            '0' => 0,
+            '-' => -1,
+            '=' => -2,
+            _ => panic!("Bad SNAFU digit '{}' in \"{}\"!", c, s),
+        };
+        acc * 5 + digit
+    })
+}
+
+/// Converts the given value into its SNAFU number (balanced base-5) representation. Negative values
+/// are supported, with the sign carried by the leading digit.
+pub fn to_snafu(n: i64) -> String {
+    let mut value = n as i128;
+    let mut output: Vec<char> = vec![];
+    loop {
+        // Digits 3 and 4 are represented as -2 and -1 with a carry into the next place
+        let digit = match value.rem_euclid(5) {
+            3 => -2,
+            4 => -1,
+            d => d,
+        };
+        output.push(match digit {
+            -2 => '=',
+            -1 => '-',
+            0 => '0',
+            1 => '1',
+            _ => '2',
+        });
+        value = (value - digit) / 5;
+        if value == 0 {
+            break;
+        }
+    }
+    output.iter().rev().collect::<String>()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Decimal and SNAFU pairs given in the problem description.
+    const KNOWN_PAIRS: [(i64, &str); 15] = [
+        (1, "1"),
+        (2, "2"),
+        (3, "1="),
+        (4, "1-"),
+        (5, "10"),
+        (8, "2="),
+        (10, "20"),
+        (15, "1=0"),
+        (20, "1-0"),
+        (976, "2=-01"),
+        (2022, "1=11-2"),
+        (4890, "2=-1=0"),
+        (12345, "1-0---0"),
+        (314159265, "1121-1110-1=0"),
+        (1747, "1=-0-2"),
+    ];
+
+    /// Tests converting the known pairs in both directions.
+    #[test]
+    fn test_snafu_known_pairs() {
+        for (decimal, snafu) in KNOWN_PAIRS {
+            assert_eq!(decimal, from_snafu(snafu), "{}", snafu);
+            assert_eq!(snafu, to_snafu(decimal), "{}", decimal);
+        }
+    }
+
+    /// Tests round-tripping zero, negative values and the limits of the i64 range.
+    #[test]
+    fn test_snafu_round_trip() {
+        assert_eq!("0", to_snafu(0));
+        assert_eq!("-", to_snafu(-1));
+        assert_eq!("=", to_snafu(-2));
+        for value in [-976, -12345, -3, 7, i64::MAX, i64::MIN + 1] {
+            assert_eq!(value, from_snafu(&to_snafu(value)), "{}", value);
+        }
+    }
+}
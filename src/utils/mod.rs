@@ -1,3 +1,11 @@
 // Module for utility code. Can be shared across the solutions for different days.
 pub mod cartography;
+pub mod graph;
+pub mod intervals;
+pub mod math;
+pub mod parsing;
+pub mod runner;
+pub mod sequence;
+pub mod simulation;
+pub mod structures;
 pub mod wildlife;
@@ -0,0 +1,39 @@
+use std::str::FromStr;
+
+/// Parses each comma-separated field of the given line, with whitespace around each field ignored.
+/// Returns the error from the first field that fails to parse. An empty line gives an empty vector.
+pub fn parse_csv_row<T: FromStr>(line: &str) -> Result<Vec<T>, T::Err> {
+    if line.trim().is_empty() {
+        return Ok(vec![]);
+    }
+    line.split(',')
+        .map(|field| field.trim().parse::<T>())
+        .collect::<Result<Vec<T>, T::Err>>()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests parsing a row of integers with whitespace around the fields.
+    #[test]
+    fn test_parse_csv_row_ints() {
+        let row = parse_csv_row::<i64>("1, 2, 3");
+        assert_eq!(Ok(vec![1, 2, 3]), row);
+        let row = parse_csv_row::<i64>(" -4,5 ,6");
+        assert_eq!(Ok(vec![-4, 5, 6]), row);
+    }
+
+    /// Tests that a malformed field results in an error.
+    #[test]
+    fn test_parse_csv_row_malformed() {
+        assert!(parse_csv_row::<i64>("1, two, 3").is_err());
+        assert!(parse_csv_row::<i64>("1,,3").is_err());
+    }
+
+    /// Tests that an empty line gives an empty row.
+    #[test]
+    fn test_parse_csv_row_empty() {
+        assert_eq!(Ok(vec![]), parse_csv_row::<u64>("  "));
+    }
+}
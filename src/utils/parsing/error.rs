@@ -0,0 +1,58 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Represents the errors that can occur when reading and parsing a problem input file.
+#[derive(Debug)]
+pub enum AocError {
+    /// The input file could not be read.
+    Io(io::Error),
+    /// A line of the input could not be parsed. Line numbers start at 1.
+    Parse { line: usize, reason: String },
+    /// The input as a whole is not in the expected format.
+    Format(String),
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Io(err) => write!(f, "failed to read input: {}", err),
+            AocError::Parse { line, reason } => write!(f, "bad input on line {}: {}", line, reason),
+            AocError::Format(reason) => write!(f, "bad input format: {}", reason),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(err: io::Error) -> Self {
+        AocError::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the display format of each error variant.
+    #[test]
+    fn test_aoc_error_display() {
+        let err = AocError::Parse {
+            line: 3,
+            reason: String::from("bad number"),
+        };
+        assert_eq!("bad input on line 3: bad number", err.to_string());
+        let err = AocError::Format(String::from("no root"));
+        assert_eq!("bad input format: no root", err.to_string());
+        let err = AocError::from(io::Error::new(io::ErrorKind::NotFound, "missing"));
+        assert_eq!("failed to read input: missing", err.to_string());
+    }
+}
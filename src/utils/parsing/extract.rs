@@ -0,0 +1,64 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref REGEX_INT: Regex = Regex::new(r"-?\d+").unwrap();
+    static ref REGEX_UINT: Regex = Regex::new(r"\d+").unwrap();
+}
+
+/// Extracts all of the signed integers from the given line, in the order they appear. A minus sign
+/// immediately preceding a number is treated as the sign of the number. Numbers that do not fit in
+/// an i64 are skipped.
+pub fn extract_ints(line: &str) -> Vec<i64> {
+    REGEX_INT
+        .find_iter(line)
+        .filter_map(|m| m.as_str().parse::<i64>().ok())
+        .collect::<Vec<i64>>()
+}
+
+/// Extracts all of the unsigned integers from the given line, in the order they appear. A minus
+/// sign is treated as a separator rather than the sign of the following number. Numbers that do
+/// not fit in a u64 are skipped.
+pub fn extract_uints(line: &str) -> Vec<u64> {
+    REGEX_UINT
+        .find_iter(line)
+        .filter_map(|m| m.as_str().parse::<u64>().ok())
+        .collect::<Vec<u64>>()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the signed extractor keeps the minus sign of a negative number.
+    #[test]
+    fn test_extract_ints_negative() {
+        let line = "Sensor at x=-5, y=12: closest beacon is at x=3-4, y=0";
+        assert_eq!(vec![-5, 12, 3, -4, 0], extract_ints(line));
+    }
+
+    /// Tests that the unsigned extractor treats a minus sign as a separator.
+    #[test]
+    fn test_extract_uints_negative() {
+        let line = "Sensor at x=-5, y=12: closest beacon is at x=3-4, y=0";
+        assert_eq!(vec![5, 12, 3, 4, 0], extract_uints(line));
+    }
+
+    /// Tests extraction from a line containing no numbers.
+    #[test]
+    fn test_extract_no_numbers() {
+        assert!(extract_ints("no numbers here").is_empty());
+        assert!(extract_uints("no numbers here").is_empty());
+    }
+
+    /// Tests that numbers too large for the integer type are skipped rather than causing a panic.
+    #[test]
+    fn test_extract_overflowing_numbers() {
+        let line = "a=99999999999999999999, b=-99999999999999999999, c=7";
+        assert_eq!(vec![7], extract_ints(line));
+        assert_eq!(vec![7], extract_uints(line));
+        let line = "max=9223372036854775807 umax=18446744073709551615";
+        assert_eq!(vec![i64::MAX], extract_ints(line));
+        assert_eq!(vec![i64::MAX as u64, u64::MAX], extract_uints(line));
+    }
+}
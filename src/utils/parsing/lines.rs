@@ -0,0 +1,109 @@
+use std::fs;
+
+use super::AocError;
+
+/// Reads the lines of the given input file, with each line trimmed and empty lines skipped. If
+/// skip comments is set, lines starting with '#' are also skipped so that input files can be
+/// annotated with notes.
+pub fn read_input_lines(path: &str, skip_comments: bool) -> Result<Vec<String>, AocError> {
+    let raw_input = fs::read_to_string(path)?;
+    Ok(input_lines(&raw_input, skip_comments))
+}
+
+/// Splits the raw input into lines, with each line trimmed and empty lines skipped. If skip
+/// comments is set, lines starting with '#' are also skipped.
+pub fn input_lines(raw_input: &str, skip_comments: bool) -> Vec<String> {
+    raw_input
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .filter(|line| !(skip_comments && line.starts_with('#')))
+        .map(String::from)
+        .collect::<Vec<String>>()
+}
+
+/// Parses each non-empty line of the raw input with the given parse function, with each line
+/// trimmed before parsing. If a line cannot be parsed, the returned error gives the line number
+/// (starting at 1, and counting empty lines) along with the reason and the content of the line.
+pub fn parse_lines<T>(
+    raw_input: &str,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Result<Vec<T>, AocError> {
+    let mut output: Vec<T> = vec![];
+    for (i, line) in raw_input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let value = parse(line).map_err(|reason| AocError::Parse {
+            line: i + 1,
+            reason: format!("{} in \"{}\"", reason, line),
+        })?;
+        output.push(value);
+    }
+    Ok(output)
+}
+
+/// Splits the raw input into records of the given number of lines, with each line trimmed and
+/// empty lines skipped. The final record is shorter than the others if the number of lines is not
+/// a multiple of the record size. Panics if the size is zero.
+pub fn chunk_lines(input: &str, size: usize) -> Vec<Vec<String>> {
+    input_lines(input, false)
+        .chunks(size)
+        .map(|chunk| chunk.to_vec())
+        .collect::<Vec<Vec<String>>>()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that blank lines are dropped, and comment lines are dropped only when requested.
+    #[test]
+    fn test_input_lines_skip_comments() {
+        let raw_input = "# cubes from the example\n1,1,1\n\n  2,1,1  \n# end of input\n";
+        let expected = vec![String::from("1,1,1"), String::from("2,1,1")];
+        assert_eq!(expected, input_lines(raw_input, true));
+        assert_eq!(4, input_lines(raw_input, false).len());
+    }
+
+    /// Tests that a missing input file results in an IO error.
+    #[test]
+    fn test_read_input_lines_missing_file() {
+        let result = read_input_lines("./input/test/missing.txt", true);
+        assert!(matches!(result, Err(AocError::Io(_))));
+    }
+
+    /// Tests chunking nine lines into three records of three lines.
+    #[test]
+    fn test_chunk_lines_even() {
+        let raw_input = "a\nb\nc\nd\ne\nf\ng\nh\ni\n";
+        let chunks = chunk_lines(raw_input, 3);
+        assert_eq!(3, chunks.len());
+        assert_eq!(vec!["a", "b", "c"], chunks[0]);
+        assert_eq!(vec!["g", "h", "i"], chunks[2]);
+    }
+
+    /// Tests that the final record is short when the lines do not divide evenly.
+    #[test]
+    fn test_chunk_lines_short_final_chunk() {
+        let raw_input = "1\n2\n\n3\n4\n5\n";
+        let chunks = chunk_lines(raw_input, 2);
+        assert_eq!(3, chunks.len());
+        assert_eq!(vec!["3", "4"], chunks[1]);
+        assert_eq!(vec!["5"], chunks[2]);
+        assert!(chunk_lines("", 2).is_empty());
+    }
+
+    /// Tests that a bad line in the input is reported with its line number and content.
+    #[test]
+    fn test_parse_lines_bad_line() {
+        let parse = |line: &str| line.parse::<u64>().map_err(|e| e.to_string());
+        assert_eq!(vec![1, 2, 3], parse_lines("1\n\n2\n 3 \n", parse).unwrap());
+        let err = parse_lines("12\n1x\n30\n", parse).unwrap_err();
+        assert!(matches!(err, AocError::Parse { line: 2, .. }));
+        let message = err.to_string();
+        assert!(message.contains("line 2"), "{}", message);
+        assert!(message.contains("\"1x\""), "{}", message);
+    }
+}
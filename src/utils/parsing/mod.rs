@@ -0,0 +1,11 @@
+mod csv;
+mod error;
+mod extract;
+mod lines;
+mod moves;
+
+pub use self::csv::parse_csv_row;
+pub use self::error::AocError;
+pub use self::extract::{extract_ints, extract_uints};
+pub use self::lines::{chunk_lines, input_lines, parse_lines, read_input_lines};
+pub use self::moves::{parse_move, parse_moves};
@@ -0,0 +1,57 @@
+use crate::utils::cartography::CardinalDirection;
+
+use super::{parse_lines, AocError};
+
+/// Parses each non-empty line of the raw input as a move instruction, such as "R 4". Returned value
+/// is the direction and number of steps for each move, or an error giving the line number of the
+/// first line that could not be parsed.
+pub fn parse_moves(input: &str) -> Result<Vec<(CardinalDirection, u64)>, AocError> {
+    parse_lines(input, parse_move)
+}
+
+/// Parses a single move instruction, given as a direction character (see
+/// CardinalDirection::from_char()) and number of steps separated by whitespace.
+pub fn parse_move(line: &str) -> Result<(CardinalDirection, u64), String> {
+    let (dirn, steps) = line
+        .trim()
+        .split_once(char::is_whitespace)
+        .ok_or_else(|| String::from("missing number of steps"))?;
+    let mut dirn_chars = dirn.chars();
+    let dirn = match (dirn_chars.next(), dirn_chars.next()) {
+        (Some(c), None) => CardinalDirection::from_char(c),
+        _ => None,
+    }
+    .ok_or_else(|| format!("bad direction \"{}\"", dirn))?;
+    let steps = steps
+        .trim()
+        .parse::<u64>()
+        .map_err(|_| format!("bad number of steps \"{}\"", steps.trim()))?;
+    Ok((dirn, steps))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests parsing move instructions into their directions and numbers of steps.
+    #[test]
+    fn test_parse_moves() {
+        let moves = parse_moves("R 4\nU 3\n\n< 12\n").unwrap();
+        let expected = vec![
+            (CardinalDirection::East, 4),
+            (CardinalDirection::North, 3),
+            (CardinalDirection::West, 12),
+        ];
+        assert_eq!(expected, moves);
+    }
+
+    /// Tests that a move with a bad direction character is rejected with its line number.
+    #[test]
+    fn test_parse_moves_bad_direction() {
+        let result = parse_moves("R 4\nX 3\n");
+        assert!(matches!(result, Err(AocError::Parse { line: 2, .. })));
+        assert!(parse_move("RU 3").is_err());
+        assert!(parse_move("R x").is_err());
+        assert!(parse_move("R").is_err());
+    }
+}
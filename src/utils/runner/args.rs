@@ -0,0 +1,35 @@
+/// Gets the input file path from the command line arguments, given in the form
+/// "<program> [input_file]". The default input file path is returned if no path was given, so
+/// each day can be run against sample inputs without editing its hard-coded input file.
+pub fn input_file_arg(args: impl IntoIterator<Item = String>, default: &str) -> String {
+    args.into_iter()
+        .nth(1)
+        .unwrap_or_else(|| String::from(default))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the input file argument overrides the default input file.
+    #[test]
+    fn test_input_file_arg_given() {
+        let args = vec![
+            String::from("day12"),
+            String::from("./input/test/day12_t001.txt"),
+        ];
+        let input_file = input_file_arg(args, "./input/day12.txt");
+        assert_eq!("./input/test/day12_t001.txt", input_file);
+    }
+
+    /// Tests that the default input file is used if no argument is given.
+    #[test]
+    fn test_input_file_arg_default() {
+        let input_file = input_file_arg(vec![String::from("day12")], "./input/day12.txt");
+        assert_eq!("./input/day12.txt", input_file);
+        assert_eq!(
+            "./input/day12.txt",
+            input_file_arg(vec![], "./input/day12.txt")
+        );
+    }
+}
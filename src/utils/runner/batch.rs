@@ -0,0 +1,61 @@
+use std::fs;
+use std::path::Path;
+
+use crate::utils::parsing::AocError;
+
+/// Checks if the given input path is a directory of input files to be run in batch mode.
+pub fn is_batch_input(path: &str) -> bool {
+    Path::new(path).is_dir()
+}
+
+/// Gets the paths of the input files within the given directory, sorted by path so that batch runs
+/// are reported in a consistent order. Subdirectories are skipped.
+pub fn batch_input_files(dir: &str) -> Result<Vec<String>, AocError> {
+    let mut paths: Vec<String> = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() {
+            paths.push(path.to_string_lossy().into_owned());
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Runs the report function against each input file within the given directory. Returned value is
+/// the reports for all of the input files, each preceded by the path of its input file.
+pub fn run_batch(dir: &str, report_for: impl Fn(&str) -> String) -> Result<String, AocError> {
+    let mut output = String::new();
+    for path in batch_input_files(dir)? {
+        output += &format!("[#] Input file: {}\n", path);
+        output += &report_for(&path);
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the input files in a directory are found in sorted order.
+    #[test]
+    fn test_batch_input_files() {
+        let expected = vec![
+            String::from("./input/test/day06_batch/a.txt"),
+            String::from("./input/test/day06_batch/b.txt"),
+        ];
+        assert_eq!(
+            expected,
+            batch_input_files("./input/test/day06_batch").unwrap()
+        );
+        assert!(is_batch_input("./input/test/day06_batch"));
+        assert!(!is_batch_input("./input/test/day06_batch/a.txt"));
+    }
+
+    /// Tests that a missing batch directory results in an IO error.
+    #[test]
+    fn test_run_batch_missing_dir() {
+        let result = run_batch("./input/test/missing_batch", |_| String::new());
+        assert!(matches!(result, Err(AocError::Io(_))));
+    }
+}
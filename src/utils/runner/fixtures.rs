@@ -0,0 +1,36 @@
+use std::fs;
+
+/// Gets the path of the named test input file for the given day, in the form
+/// "./input/test/dayNN_name.txt" (for example, "./input/test/day19_t001.txt").
+pub fn test_input_path(day: u64, name: &str) -> String {
+    format!("./input/test/day{:02}_{}.txt", day, name)
+}
+
+/// Loads the contents of the named test input file for the given day. Panics if the file cannot be
+/// read, since a missing fixture is an error in the test setup.
+pub fn load_test_input(day: u64, name: &str) -> String {
+    let path = test_input_path(day, name);
+    match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) => panic!("Failed to load test input file {}: {}", path, e),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the test input path is built with a zero-padded day number.
+    #[test]
+    fn test_test_input_path() {
+        assert_eq!("./input/test/day09_t002.txt", test_input_path(9, "t002"));
+        assert_eq!("./input/test/day19_t001.txt", test_input_path(19, "t001"));
+    }
+
+    /// Tests that the contents of an existing test input file are loaded.
+    #[test]
+    fn test_load_test_input() {
+        let contents = load_test_input(19, "t001");
+        assert!(contents.starts_with("Blueprint 1:"));
+    }
+}
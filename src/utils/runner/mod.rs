@@ -0,0 +1,13 @@
+mod args;
+mod batch;
+mod fixtures;
+mod report;
+mod style;
+mod timing;
+
+pub use self::args::input_file_arg;
+pub use self::batch::{batch_input_files, is_batch_input, run_batch};
+pub use self::fixtures::{load_test_input, test_input_path};
+pub use self::report::{format_results, print_results, run_day};
+pub use self::style::{style_answer, style_timing, use_color, use_color_for};
+pub use self::timing::{timed, Timings};
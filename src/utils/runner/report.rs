@@ -0,0 +1,233 @@
+use std::fmt::Display;
+
+use super::{style_answer, style_timing, timed, use_color, Timings};
+
+/// Processes the input file for the given day and solves both parts of the problem, timing each
+/// step. Returned value is the decorated report of the solutions and execution times.
+pub fn run_day<I, A, B>(
+    day: u64,
+    name: &str,
+    input_file: &str,
+    process_input: impl FnOnce(&str) -> I,
+    solve_part1: impl FnOnce(&I) -> A,
+    solve_part2: impl FnOnce(&I) -> B,
+) -> String
+where
+    A: Display,
+    B: Display,
+{
+    // Input processing
+    let (input, input_parser_duration) = timed(|| process_input(input_file));
+    // Solve part 1
+    let (p1_solution, p1_duration) = timed(|| solve_part1(&input));
+    // Solve part 2
+    let (p2_solution, p2_duration) = timed(|| solve_part2(&input));
+    // Generate results
+    let timings = Timings {
+        input: input_parser_duration,
+        part1: p1_duration,
+        part2: p2_duration,
+    };
+    format_results(day, name, p1_solution, p2_solution, &timings, use_color())
+}
+
+/// Formats the results block for a day's problem, containing the problem details, the solutions to
+/// both parts and the execution times. Answers and timings are styled if colour is set.
+pub fn format_results(
+    day: u64,
+    name: &str,
+    part1: impl Display,
+    part2: impl Display,
+    timings: &Timings,
+    color: bool,
+) -> String {
+    let answer = |solution: String| style_answer(&solution, color);
+    let timing = |duration: String| style_timing(&duration, color);
+    let mut output = String::new();
+    output += "==================================================\n";
+    output += &format!("AOC 2022 Day {} - \"{}\"\n", day, name);
+    output += &format!("[+] Part 1: {}\n", answer(part1.to_string()));
+    output += &format!("[+] Part 2: {}\n", answer(part2.to_string()));
+    output += "~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~\n";
+    output += "Execution times:\n";
+    output += &format!("[+] Input:  {}\n", timing(format!("{:.2?}", timings.input)));
+    output += &format!("[+] Part 1: {}\n", timing(format!("{:.2?}", timings.part1)));
+    output += &format!("[+] Part 2: {}\n", timing(format!("{:.2?}", timings.part2)));
+    output += &format!(
+        "[*] TOTAL:  {}\n",
+        timing(format!("{:.2?}", timings.total()))
+    );
+    output += "==================================================\n";
+    output
+}
+
+/// Prints the results block for a day's problem to stdout, styled if colour is enabled.
+pub fn print_results(
+    day: u64,
+    name: &str,
+    part1: impl Display,
+    part2: impl Display,
+    timings: &Timings,
+) {
+    print!(
+        "{}",
+        format_results(day, name, part1, part2, timings, use_color())
+    );
+}
+
+/// Generates the standard `main` function for a day's solution binary, which processes the input
+/// file, solves both parts of the problem and prints the results with execution times. The input
+/// file can be overridden by passing a path as the first command line argument. If the path is a
+/// directory, the solution is run in batch mode against every input file in the directory. A
+/// `generate_report` function is also generated, which returns the printed results for the default
+/// input file as a string, along with `generate_report_for` which does the same for a given input
+/// file and `generate_batch_report` which does the same for a directory of input files.
+///
+/// Arguments are the problem day, problem name, input file path, input processing function and the
+/// Part 1 and Part 2 solver functions.
+#[macro_export]
+macro_rules! aoc_main {
+    (
+        $day:expr,
+        $name:expr,
+        $input_file:expr,
+        $process_input:expr,
+        $solve_part1:expr,
+        $solve_part2:expr $(,)?
+    ) => {
+        /// Processes the default input file and solves both parts of the problem. Returned value
+        /// is the report of the solutions and execution times.
+        #[allow(dead_code)]
+        fn generate_report() -> String {
+            generate_report_for($input_file)
+        }
+
+        /// Processes the given input file and solves both parts of the problem. Returned value is
+        /// the report of the solutions and execution times.
+        fn generate_report_for(input_file: &str) -> String {
+            $crate::utils::runner::run_day(
+                $day,
+                $name,
+                input_file,
+                $process_input,
+                |input| $solve_part1(input),
+                |input| $solve_part2(input),
+            )
+        }
+
+        /// Processes each input file in the given directory and solves both parts of the problem.
+        /// Returned value is the reports for all of the input files.
+        fn generate_batch_report(dir: &str) -> Result<String, $crate::utils::parsing::AocError> {
+            $crate::utils::runner::run_batch(dir, generate_report_for)
+        }
+
+        /// Processes the input file (the default, or the path given as the first command line
+        /// argument) and solves both parts of the problem. Solutions are printed to stdout. If the
+        /// path given is a directory, each input file in the directory is processed in turn.
+        pub fn main() {
+            let input_file = $crate::utils::runner::input_file_arg(std::env::args(), $input_file);
+            if $crate::utils::runner::is_batch_input(&input_file) {
+                match generate_batch_report(&input_file) {
+                    Ok(report) => print!("{}", report),
+                    Err(err) => panic!("Batch run failed for {}: {}", input_file, err),
+                }
+            } else {
+                print!("{}", generate_report_for(&input_file));
+            }
+        }
+    };
+}
+
+/// Generates the standard pair of tests checking a day's Part 1 and Part 2 solver functions against
+/// the actual problem solutions. Each generated test processes the input file and compares the result
+/// of the solver with the expected value. Test names are given so the `test_dayNN_partX_actual`
+/// naming used across the days is kept.
+///
+/// Arguments are the input file path, input processing function and, for each part, the test name
+/// followed by `:`, the solver function, `=>` and the expected solution.
+#[macro_export]
+macro_rules! aoc_tests {
+    (
+        $input_file:expr,
+        $process_input:expr,
+        $part1_test:ident: $solve_part1:expr => $part1_expected:expr,
+        $part2_test:ident: $solve_part2:expr => $part2_expected:expr $(,)?
+    ) => {
+        /// Tests the Part 1 solver method against the actual problem solution.
+        #[test]
+        fn $part1_test() {
+            let input = ($process_input)($input_file);
+            let solution = $solve_part1(&input);
+            assert_eq!($part1_expected, solution);
+        }
+
+        /// Tests the Part 2 solver method against the actual problem solution.
+        #[test]
+        fn $part2_test() {
+            let input = ($process_input)($input_file);
+            let solution = $solve_part2(&input);
+            assert_eq!($part2_expected, solution);
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Tests that the report contains the problem details and both solutions.
+    #[test]
+    fn test_run_day_report() {
+        let report = run_day(
+            99,
+            "Test Problem",
+            "1 2 3",
+            |raw| {
+                raw.split(' ')
+                    .map(|v| v.parse::<u64>().unwrap())
+                    .collect::<Vec<u64>>()
+            },
+            |values| values.iter().sum::<u64>(),
+            |values| values.iter().product::<u64>(),
+        );
+        let lines = report.lines().collect::<Vec<&str>>();
+        assert_eq!(11, lines.len());
+        assert_eq!("AOC 2022 Day 99 - \"Test Problem\"", lines[1]);
+        assert_eq!("[+] Part 1: 6", lines[2]);
+        assert_eq!("[+] Part 2: 6", lines[3]);
+    }
+
+    /// Tests the formatted results block for fixed solutions and timings.
+    #[test]
+    fn test_format_results() {
+        let timings = Timings {
+            input: Duration::from_micros(1500),
+            part1: Duration::from_millis(2),
+            part2: Duration::from_secs(1),
+        };
+        let expected = concat!(
+            "==================================================\n",
+            "AOC 2022 Day 7 - \"No Space Left On Device\"\n",
+            "[+] Part 1: 1453349\n",
+            "[+] Part 2: abc\n",
+            "~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~\n",
+            "Execution times:\n",
+            "[+] Input:  1.50ms\n",
+            "[+] Part 1: 2.00ms\n",
+            "[+] Part 2: 1.00s\n",
+            "[*] TOTAL:  1.00s\n",
+            "==================================================\n",
+        );
+        let results = format_results(
+            7,
+            "No Space Left On Device",
+            1453349,
+            "abc",
+            &timings,
+            false,
+        );
+        assert_eq!(expected, results);
+    }
+}
@@ -0,0 +1,64 @@
+use std::io::{self, IsTerminal};
+
+#[cfg(feature = "color")]
+use owo_colors::OwoColorize;
+
+/// Checks if coloured output should be used. Requires the "color" feature to be enabled and stdout
+/// to be a terminal, so output redirected to a file or pipe is left uncoloured.
+pub fn use_color() -> bool {
+    use_color_for(io::stdout().is_terminal())
+}
+
+/// Checks if coloured output should be used for an output stream, given whether or not the stream
+/// is a terminal. Requires the "color" feature to be enabled and the stream to be a terminal.
+pub fn use_color_for(is_tty: bool) -> bool {
+    cfg!(feature = "color") && is_tty
+}
+
+/// Styles the given answer text (green) if colour is enabled.
+pub fn style_answer(text: &str, color: bool) -> String {
+    #[cfg(feature = "color")]
+    if color {
+        return text.green().to_string();
+    }
+    let _ = color;
+    text.to_string()
+}
+
+/// Styles the given timing text (dimmed) if colour is enabled.
+pub fn style_timing(text: &str, color: bool) -> String {
+    #[cfg(feature = "color")]
+    if color {
+        return text.dimmed().to_string();
+    }
+    let _ = color;
+    text.to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that no colour is used when the output is not a terminal, with or without the "color"
+    /// feature enabled, and that uncoloured text is returned unchanged.
+    #[test]
+    fn test_style_stripped_when_not_terminal() {
+        assert!(!use_color_for(false));
+        assert_eq!("42", style_answer("42", false));
+        assert_eq!("1.23ms", style_timing("1.23ms", false));
+    }
+
+    /// Tests that colour is only used for terminal output if the "color" feature is enabled.
+    #[test]
+    fn test_use_color_for_terminal() {
+        assert_eq!(cfg!(feature = "color"), use_color_for(true));
+    }
+
+    /// Tests that colour escape codes are added when colour is enabled.
+    #[cfg(feature = "color")]
+    #[test]
+    fn test_style_colored() {
+        assert_eq!("\u{1b}[32m42\u{1b}[39m", style_answer("42", true));
+        assert!(style_timing("1.23ms", true).starts_with('\u{1b}'));
+    }
+}
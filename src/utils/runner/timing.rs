@@ -0,0 +1,40 @@
+use std::time::{Duration, Instant};
+
+/// Runs the given closure, returning its result along with the time taken for it to run.
+pub fn timed<F, R>(f: F) -> (R, Duration)
+where
+    F: FnOnce() -> R,
+{
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Holds the execution times of the steps taken to solve a day's problem.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Timings {
+    pub input: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    /// Gets the total execution time across all of the steps.
+    pub fn total(&self) -> Duration {
+        self.input + self.part1 + self.part2
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the value returned by the closure is passed through unchanged.
+    #[test]
+    fn test_timed_returns_value() {
+        let (result, _duration) = timed(|| (1..=10).sum::<u64>());
+        assert_eq!(55, result);
+        let (result, _duration) = timed(|| String::from("abc"));
+        assert_eq!("abc", result);
+    }
+}
@@ -0,0 +1,117 @@
+/// Represents a circular list of values that can be rearranged by moving values forward or
+/// backward around the list. Each value remembers its original position in the list, so values can
+/// be moved in their original order however the list has been rearranged.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CircularList<T> {
+    items: Vec<(usize, T)>,
+}
+
+impl<T> CircularList<T> {
+    /// Creates a new circular list holding the given values in order.
+    pub fn new(values: Vec<T>) -> Self {
+        Self {
+            items: values.into_iter().enumerate().collect(),
+        }
+    }
+
+    /// Gets the number of values in the list.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Checks if the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Gets the values in their current order, starting from the front of the list.
+    pub fn values(&self) -> Vec<&T> {
+        self.items.iter().map(|(_, value)| value).collect()
+    }
+
+    /// Gets the current index of the value that was at the given index when the list was created.
+    pub fn position_of_original(&self, original: usize) -> Option<usize> {
+        self.items.iter().position(|(orig, _)| *orig == original)
+    }
+
+    /// Moves the value at the given index forward (or backward, if negative) around the list by the
+    /// given amount. Since the value is removed from the list while it moves, it passes over the
+    /// other len - 1 values, so the new index wraps around modulo len - 1. A value that would land
+    /// at the front of the list is placed at index 0.
+    pub fn move_by(&mut self, index: usize, amount: i64) {
+        let len = self.items.len();
+        if len <= 1 {
+            return;
+        }
+        let new_index = (index as i64 + amount).rem_euclid(len as i64 - 1) as usize;
+        if new_index < index {
+            self.items[new_index..=index].rotate_right(1);
+        } else if new_index > index {
+            self.items[index..=new_index].rotate_left(1);
+        }
+    }
+
+    /// Mixes the list for the given number of rounds. In each round, every value is moved by the
+    /// amount given by the amount function, in the order the values were originally given.
+    pub fn mix(&mut self, rounds: usize, amount: impl Fn(&T) -> i64) {
+        for _ in 0..rounds {
+            for original in 0..self.items.len() {
+                let index = self.position_of_original(original).unwrap();
+                let value_amount = amount(&self.items[index].1);
+                self.move_by(index, value_amount);
+            }
+        }
+    }
+}
+
+impl<T: PartialEq> CircularList<T> {
+    /// Gets the value the given number of places after the first occurrence of the target value,
+    /// wrapping around the list as needed. Returns None if the target is not in the list.
+    pub fn value_at_offset_from(&self, target: &T, offset: usize) -> Option<&T> {
+        let index = self.items.iter().position(|(_, value)| value == target)?;
+        Some(&self.items[(index + offset) % self.items.len()].1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Creates the circular list from the mixing example given in the problem description.
+    fn example_list() -> CircularList<i64> {
+        CircularList::new(vec![1, 2, -3, 3, -2, 0, 4])
+    }
+
+    /// Gets the values of the list in order starting from the given value.
+    fn values_from(list: &CircularList<i64>, start: i64) -> Vec<i64> {
+        (0..list.len())
+            .map(|offset| *list.value_at_offset_from(&start, offset).unwrap())
+            .collect()
+    }
+
+    /// Tests the individual moves from the mixing example, including moves that wrap around.
+    #[test]
+    fn test_circular_list_move_by_example() {
+        let mut list = example_list();
+        list.move_by(0, 1);
+        assert_eq!(vec![&2, &1, &-3, &3, &-2, &0, &4], list.values());
+        list.move_by(0, 2);
+        assert_eq!(vec![&1, &-3, &2, &3, &-2, &0, &4], list.values());
+        list.move_by(1, -3);
+        assert_eq!(vec![1, 2, 3, -2, -3, 0, 4], values_from(&list, 1));
+    }
+
+    /// Tests mixing the example list once, and finding the grove coordinates from the result.
+    #[test]
+    fn test_circular_list_mix_example() {
+        let mut list = example_list();
+        list.mix(1, |value| *value);
+        assert_eq!(vec![1, 2, -3, 4, 0, 3, -2], values_from(&list, 1));
+        let coords = [1000, 2000, 3000]
+            .iter()
+            .map(|offset| *list.value_at_offset_from(&0, *offset).unwrap())
+            .collect::<Vec<i64>>();
+        assert_eq!(vec![4, -3, 2], coords);
+        assert_eq!(None, list.value_at_offset_from(&7, 1));
+    }
+}
@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Counts the number of times each distinct item occurs in the given items.
+pub fn frequency_map<T: Hash + Eq + Clone>(
+    items: impl IntoIterator<Item = T>,
+) -> HashMap<T, usize> {
+    let mut counts: HashMap<T, usize> = HashMap::new();
+    for item in items {
+        *counts.entry(item).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests counting the characters in a string.
+    #[test]
+    fn test_frequency_map_chars() {
+        let counts = frequency_map("mississippi".chars());
+        assert_eq!(4, counts.len());
+        assert_eq!(1, counts[&'m']);
+        assert_eq!(4, counts[&'i']);
+        assert_eq!(4, counts[&'s']);
+        assert_eq!(2, counts[&'p']);
+        assert!(frequency_map(Vec::<u64>::new()).is_empty());
+    }
+}
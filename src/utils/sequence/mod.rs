@@ -0,0 +1,9 @@
+mod circular;
+mod frequency;
+mod window;
+
+pub use self::circular::CircularList;
+pub use self::frequency::frequency_map;
+pub use self::window::{
+    find_marker, find_marker_in_iter, find_marker_with_window, first_distinct_window,
+};
@@ -0,0 +1,142 @@
+use std::collections::VecDeque;
+
+/// Finds the first window of the given length in which all of the items are different. Returned
+/// value is the index of the first item in the window, or None if there is no such window.
+///
+/// The window of distinct items is kept as a slice of the input, with each new item checked against
+/// the window directly rather than through a hash set, so the result does not depend on hashing.
+pub fn first_distinct_window<T: PartialEq>(items: &[T], len: usize) -> Option<usize> {
+    if len == 0 {
+        return Some(0);
+    }
+    let mut start = 0;
+    for (i, item) in items.iter().enumerate() {
+        // Move the window start past the previous copy of the item, if it is in the window
+        if let Some(pos) = items[start..i].iter().position(|x| x == item) {
+            start += pos + 1;
+        }
+        if i + 1 - start == len {
+            return Some(start);
+        }
+    }
+    None
+}
+
+/// Finds the first marker of the given length (run of consecutive items that are all different).
+/// Returned value is the number of items that need to be processed before the marker is observed
+/// (the index just past the end of the marker), or None if there is no such marker.
+pub fn find_marker<T: PartialEq>(items: &[T], marker_len: usize) -> Option<usize> {
+    first_distinct_window(items, marker_len).map(|start| start + marker_len)
+}
+
+/// Finds the first marker of the given length in the same way as find_marker(), but by scanning the
+/// items produced by the iterator so the whole input does not need to be held in memory. A rolling
+/// window of the most recent distinct items is maintained.
+pub fn find_marker_in_iter<T: PartialEq>(
+    items: impl Iterator<Item = T>,
+    marker_len: usize,
+) -> Option<usize> {
+    if marker_len == 0 {
+        return Some(0);
+    }
+    let mut window: VecDeque<T> = VecDeque::with_capacity(marker_len);
+    for (i, item) in items.enumerate() {
+        // Drop items from the window up to and including the previous copy of the item
+        if let Some(pos) = window.iter().position(|x| *x == item) {
+            window.drain(..=pos);
+        }
+        window.push_back(item);
+        if window.len() == marker_len {
+            return Some(i + 1);
+        }
+    }
+    None
+}
+
+/// Finds the first marker of the given length in the same way as find_marker(), also returning the
+/// items that formed the marker so the detection can be checked visually. Returned value is the
+/// tuple of the marker index and the marker items in the order they appear in the input.
+pub fn find_marker_with_window<T: PartialEq + Clone>(
+    items: &[T],
+    marker_len: usize,
+) -> Option<(usize, Vec<T>)> {
+    let start = first_distinct_window(items, marker_len)?;
+    let end = start + marker_len;
+    Some((end, items[start..end].to_vec()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests finding the first distinct window in a slice of integers.
+    #[test]
+    fn test_first_distinct_window_ints() {
+        let items = [1, 2, 1, 3, 3, 4, 5, 6, 4];
+        assert_eq!(Some(0), first_distinct_window(&items, 2));
+        assert_eq!(Some(1), first_distinct_window(&items, 3));
+        assert_eq!(Some(4), first_distinct_window(&items, 4));
+        assert_eq!(None, first_distinct_window(&items, 5));
+        assert_eq!(None, first_distinct_window(&items[..1], 2));
+    }
+
+    /// Tests finding the first distinct window in a slice of characters.
+    #[test]
+    fn test_first_distinct_window_chars() {
+        let chars = "mjqjpqmgbljsphdztnvjfqwrcgsmlb"
+            .chars()
+            .collect::<Vec<char>>();
+        assert_eq!(Some(3), first_distinct_window(&chars, 4));
+        assert_eq!(Some(5), first_distinct_window(&chars, 14));
+    }
+
+    /// Tests finding markers of lengths other than those used in the Day 6 problem.
+    #[test]
+    fn test_find_marker_lengths() {
+        let chars = "aabbccdefghij".chars().collect::<Vec<char>>();
+        assert_eq!(Some(3), find_marker(&chars, 2));
+        assert_eq!(Some(12), find_marker(&chars, 7));
+        assert_eq!(None, find_marker(&chars, 9));
+        assert_eq!(None, find_marker(&['a', 'b', 'c'], 7));
+        assert_eq!(Some(0), find_marker(&chars, 0));
+    }
+
+    /// Tests that the streaming marker scanner gives the same index as the slice-based version for
+    /// a sequence of integers.
+    #[test]
+    fn test_find_marker_in_iter_ints() {
+        let items = [1, 2, 1, 3, 3, 4, 5, 6, 4];
+        for marker_len in 0..6 {
+            assert_eq!(
+                find_marker(&items, marker_len),
+                find_marker_in_iter(items.iter(), marker_len)
+            );
+        }
+    }
+
+    /// Tests that the marker items returned alongside the index are all different, have the
+    /// requested length and end at the marker index.
+    #[test]
+    fn test_find_marker_with_window() {
+        let chars = "mjqjpqmgbljsphdztnvjfqwrcgsmlb"
+            .chars()
+            .collect::<Vec<char>>();
+        for marker_len in [4, 14] {
+            let (index, window) = find_marker_with_window(&chars, marker_len).unwrap();
+            assert_eq!(marker_len, window.len());
+            let mut distinct = window.clone();
+            distinct.sort();
+            distinct.dedup();
+            assert_eq!(marker_len, distinct.len());
+            assert_eq!(&chars[index - marker_len..index], window.as_slice());
+            assert_eq!(
+                first_distinct_window(&chars, marker_len),
+                Some(index - marker_len)
+            );
+        }
+        let (index, window) = find_marker_with_window(&chars, 4).unwrap();
+        assert_eq!(7, index);
+        assert_eq!(vec!['j', 'p', 'q', 'm'], window);
+        assert_eq!(None, find_marker_with_window(&chars[..3], 4));
+    }
+}
@@ -0,0 +1,70 @@
+use std::hash::Hash;
+
+/// Finds the cycle reached by repeatedly applying the step function to the initial state. Returned
+/// value is the index of the first state in the cycle (the length of the tail leading into the
+/// cycle) and the length of the cycle. The step function must be deterministic, and the sequence of
+/// states must eventually repeat or this function will not return.
+///
+/// Brent's algorithm is used, so only a few states are held at any time regardless of the length of
+/// the tail or the cycle.
+pub fn find_cycle<S: Hash + Eq + Clone>(initial: S, step: impl Fn(&S) -> S) -> (usize, usize) {
+    // Find the cycle length, with the tortoise jumping to the hare at each power of two steps
+    let mut power = 1;
+    let mut cycle_len = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == cycle_len {
+            tortoise = hare.clone();
+            power *= 2;
+            cycle_len = 0;
+        }
+        hare = step(&hare);
+        cycle_len += 1;
+    }
+    // Find the start of the cycle, with the hare kept one cycle length ahead of the tortoise
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..cycle_len {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    (start, cycle_len)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the tail and cycle lengths are found for a simple integer map with a known tail
+    /// (0, 1) and cycle (2, 3, 4).
+    #[test]
+    fn test_find_cycle_with_tail() {
+        let step = |x: &u64| if *x < 4 { x + 1 } else { 2 };
+        assert_eq!((2, 3), find_cycle(0, step));
+        assert_eq!((0, 3), find_cycle(3, step));
+    }
+
+    /// Tests that a fixed point is found as a cycle of length one.
+    #[test]
+    fn test_find_cycle_fixed_point() {
+        assert_eq!(
+            (3, 1),
+            find_cycle(8_u64, |x| if *x > 5 { x - 1 } else { 5 })
+        );
+        assert_eq!((0, 1), find_cycle(5_u64, |_| 5));
+    }
+
+    /// Tests a long tail and cycle whose lengths are not powers of two.
+    #[test]
+    fn test_find_cycle_long() {
+        let step = |x: &u64| if *x < 1000 { x + 1 } else { 263 };
+        assert_eq!((263, 738), find_cycle(0, step));
+        assert_eq!((0, 738), find_cycle(500, step));
+    }
+}
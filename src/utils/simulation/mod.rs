@@ -0,0 +1,5 @@
+mod cycle;
+mod stable;
+
+pub use self::cycle::find_cycle;
+pub use self::stable::run_until_stable;
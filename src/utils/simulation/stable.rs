@@ -0,0 +1,41 @@
+/// Repeatedly applies the step function to the state until applying it no longer changes the
+/// state, or until the maximum number of steps have been applied. Returned value is the final
+/// state and the number of steps applied. If the state stabilised, the count includes the final
+/// step that made no change (matching puzzles that ask for the first round in which nothing
+/// moves). If the cap was reached, the count is equal to the maximum number of steps.
+pub fn run_until_stable<S: PartialEq + Clone>(
+    initial: S,
+    step: impl Fn(&S) -> S,
+    max_steps: usize,
+) -> (S, usize) {
+    let mut state = initial;
+    for steps in 1..=max_steps {
+        let next = step(&state);
+        if next == state {
+            return (state, steps);
+        }
+        state = next;
+    }
+    (state, max_steps)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests a state that stabilises after a known number of steps.
+    #[test]
+    fn test_run_until_stable_stabilises() {
+        let step = |x: &u64| if *x > 5 { x - 1 } else { *x };
+        assert_eq!((5, 6), run_until_stable(10, step, 100));
+        assert_eq!((5, 1), run_until_stable(5, step, 100));
+    }
+
+    /// Tests that the simulation stops at the step cap if the state never stabilises.
+    #[test]
+    fn test_run_until_stable_hits_cap() {
+        let step = |x: &u64| x + 1;
+        assert_eq!((25, 25), run_until_stable(0, step, 25));
+        assert_eq!((7, 0), run_until_stable(7, step, 0));
+    }
+}
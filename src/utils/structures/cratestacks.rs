@@ -0,0 +1,86 @@
+/// Represents stacks of crates that can be rearranged by a crane. Stacks are identified by their
+/// index (starting at 0), and the crates within each stack are held from bottom to top.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CrateStacks {
+    stacks: Vec<Vec<char>>,
+}
+
+impl CrateStacks {
+    /// Creates new crate stacks, with the crates of each stack given from bottom to top.
+    pub fn new(stacks: Vec<Vec<char>>) -> Self {
+        Self { stacks }
+    }
+
+    /// Gets the crates in the stack with the given index, from bottom to top.
+    pub fn stack(&self, index: usize) -> Option<&[char]> {
+        self.stacks.get(index).map(|stack| stack.as_slice())
+    }
+
+    /// Moves the given number of crates from the top of one stack to the top of another. If
+    /// preserve order is set, the crates are moved all at once so they keep their order. Otherwise,
+    /// the crates are moved one at a time, which reverses their order. Panics if either stack does
+    /// not exist or the source stack holds fewer crates than requested.
+    pub fn move_crates(&mut self, count: usize, from: usize, to: usize, preserve_order: bool) {
+        let source = &mut self.stacks[from];
+        if count > source.len() {
+            panic!(
+                "Cannot move {} crates from stack {} holding {} crates!",
+                count,
+                from,
+                source.len()
+            );
+        }
+        let mut moved = source.split_off(source.len() - count);
+        if !preserve_order {
+            moved.reverse();
+        }
+        self.stacks[to].extend(moved);
+    }
+
+    /// Gets the crates at the top of each stack, in stack order. Empty stacks are skipped.
+    pub fn tops(&self) -> String {
+        self.stacks
+            .iter()
+            .filter_map(|stack| stack.last())
+            .collect::<String>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Creates the crate stacks from the example given in the problem description.
+    fn example_stacks() -> CrateStacks {
+        CrateStacks::new(vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']])
+    }
+
+    /// Move instructions from the example, given as (count, from, to) with stacks indexed from 0.
+    const EXAMPLE_MOVES: [(usize, usize, usize); 4] = [(1, 1, 0), (3, 0, 2), (2, 1, 0), (1, 0, 1)];
+
+    /// Tests moving crates one at a time, which reverses the order of the crates moved together.
+    #[test]
+    fn test_crate_stacks_move_one_at_a_time() {
+        let mut stacks = example_stacks();
+        stacks.move_crates(1, 1, 0, false);
+        stacks.move_crates(3, 0, 2, false);
+        assert_eq!(Some(['P', 'D', 'N', 'Z'].as_slice()), stacks.stack(2));
+        assert_eq!("CZ", stacks.tops());
+        for (count, from, to) in &EXAMPLE_MOVES[2..] {
+            stacks.move_crates(*count, *from, *to, false);
+        }
+        assert_eq!("CMZ", stacks.tops());
+    }
+
+    /// Tests moving crates all at once, which preserves the order of the crates moved together.
+    #[test]
+    fn test_crate_stacks_move_preserving_order() {
+        let mut stacks = example_stacks();
+        for (count, from, to) in EXAMPLE_MOVES {
+            stacks.move_crates(count, from, to, true);
+        }
+        assert_eq!("MCD", stacks.tops());
+        assert_eq!(Some(['P', 'Z', 'N', 'D'].as_slice()), stacks.stack(2));
+        assert_eq!(None, stacks.stack(3));
+    }
+}
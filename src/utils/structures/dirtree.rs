@@ -0,0 +1,139 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Represents a tree of directories containing files of known sizes, such as one rebuilt from the
+/// output of shell commands. Paths are expected to be absolute (starting from "/").
+#[derive(Clone, Default, Debug)]
+pub struct DirTree {
+    dirs: HashSet<PathBuf>,
+    files: HashMap<PathBuf, u64>,
+}
+
+impl DirTree {
+    /// Creates a new directory tree containing only the root directory.
+    pub fn new() -> Self {
+        Self {
+            dirs: HashSet::from([PathBuf::from("/")]),
+            files: HashMap::new(),
+        }
+    }
+
+    /// Adds the directory at the given path, along with any of its parent directories that have not
+    /// yet been added. Directories without any files are included in the directory sizes.
+    pub fn add_dir(&mut self, path: impl AsRef<Path>) {
+        for dir in path.as_ref().ancestors() {
+            if !self.dirs.insert(dir.to_path_buf()) {
+                break;
+            }
+        }
+    }
+
+    /// Adds the file at the given path with the given size, along with its parent directories.
+    /// Adding a file at the same path again replaces its size rather than counting it twice.
+    pub fn add_file(&mut self, path: impl AsRef<Path>, size: u64) {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            self.add_dir(parent);
+        }
+        self.files.insert(path.to_path_buf(), size);
+    }
+
+    /// Calculates the total size of each directory, including the files within all of its
+    /// subdirectories.
+    pub fn dir_sizes(&self) -> HashMap<PathBuf, u64> {
+        let mut sizes = self
+            .dirs
+            .iter()
+            .map(|dir| (dir.clone(), 0))
+            .collect::<HashMap<PathBuf, u64>>();
+        for (path, size) in &self.files {
+            for dir in path.ancestors().skip(1) {
+                *sizes.entry(dir.to_path_buf()).or_insert(0) += size;
+            }
+        }
+        sizes
+    }
+
+    /// Gets the directories with a total size of at most the given limit, sorted by path.
+    pub fn dirs_at_most(&self, limit: u64) -> Vec<(PathBuf, u64)> {
+        let mut dirs = self
+            .dir_sizes()
+            .into_iter()
+            .filter(|(_, size)| *size <= limit)
+            .collect::<Vec<(PathBuf, u64)>>();
+        dirs.sort();
+        dirs
+    }
+
+    /// Gets the smallest directory with a total size of at least the given minimum. Returns None if
+    /// no directory is large enough.
+    pub fn smallest_dir_at_least(&self, minimum: u64) -> Option<(PathBuf, u64)> {
+        self.dir_sizes()
+            .into_iter()
+            .filter(|(_, size)| *size >= minimum)
+            .min_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Builds the directory tree from the example given in the problem description.
+    fn example_tree() -> DirTree {
+        let mut tree = DirTree::new();
+        tree.add_file("/b.txt", 14848514);
+        tree.add_file("/c.dat", 8504156);
+        tree.add_file("/a/f", 29116);
+        tree.add_file("/a/g", 2557);
+        tree.add_file("/a/h.lst", 62596);
+        tree.add_file("/a/e/i", 584);
+        tree.add_file("/d/j", 4060174);
+        tree.add_file("/d/d.log", 8033020);
+        tree.add_file("/d/d.ext", 5626152);
+        tree.add_file("/d/k", 7214296);
+        tree
+    }
+
+    /// Tests the aggregated directory sizes of the example tree.
+    #[test]
+    fn test_dir_tree_dir_sizes() {
+        let sizes = example_tree().dir_sizes();
+        assert_eq!(4, sizes.len());
+        assert_eq!(584, sizes[Path::new("/a/e")]);
+        assert_eq!(94853, sizes[Path::new("/a")]);
+        assert_eq!(24933642, sizes[Path::new("/d")]);
+        assert_eq!(48381165, sizes[Path::new("/")]);
+    }
+
+    /// Tests filtering the directories of the example tree by size.
+    #[test]
+    fn test_dir_tree_filter_by_size() {
+        let tree = example_tree();
+        let small = tree.dirs_at_most(100000);
+        assert_eq!(
+            vec![(PathBuf::from("/a"), 94853), (PathBuf::from("/a/e"), 584)],
+            small
+        );
+        assert_eq!(95437, small.iter().map(|(_, size)| size).sum::<u64>());
+        let needed = 30000000 - (70000000 - 48381165);
+        assert_eq!(
+            Some((PathBuf::from("/d"), 24933642)),
+            tree.smallest_dir_at_least(needed)
+        );
+        assert_eq!(None, tree.smallest_dir_at_least(u64::MAX));
+    }
+
+    /// Tests that empty directories are included and re-added files are not counted twice.
+    #[test]
+    fn test_dir_tree_empty_dir_and_replaced_file() {
+        let mut tree = DirTree::new();
+        tree.add_dir("/x/y");
+        tree.add_file("/x/z", 10);
+        tree.add_file("/x/z", 12);
+        let sizes = tree.dir_sizes();
+        assert_eq!(0, sizes[Path::new("/x/y")]);
+        assert_eq!(12, sizes[Path::new("/x")]);
+        assert_eq!(12, sizes[Path::new("/")]);
+    }
+}
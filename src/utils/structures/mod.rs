@@ -0,0 +1,7 @@
+mod cratestacks;
+mod dirtree;
+mod nestedlist;
+
+pub use self::cratestacks::CrateStacks;
+pub use self::dirtree::DirTree;
+pub use self::nestedlist::NestedList;
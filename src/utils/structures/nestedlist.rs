@@ -0,0 +1,205 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::parsing::AocError;
+
+/// Represents a value that is either an integer or a list of nested values, such as
+/// "[[1],[2,3]]".
+///
+/// Values are ordered by comparing integers numerically and lists element by element, with the
+/// shorter list coming first if all of its elements are equal to those of the longer list. When an
+/// integer is compared with a list, the integer is treated as a list containing only that integer.
+/// Equality follows the same rules, so "[[1]]" is equal to "[1]".
+#[derive(Clone, Debug)]
+pub enum NestedList {
+    Int(i64),
+    List(Vec<NestedList>),
+}
+
+impl NestedList {
+    /// Parses the nested list value starting at the cursor, leaving the cursor after the end of the
+    /// value.
+    fn parse_value(chars: &[char], cursor: &mut usize) -> Result<NestedList, String> {
+        match chars.get(*cursor) {
+            Some('[') => {
+                *cursor += 1;
+                let mut items: Vec<NestedList> = vec![];
+                if chars.get(*cursor) == Some(&']') {
+                    *cursor += 1;
+                    return Ok(NestedList::List(items));
+                }
+                loop {
+                    items.push(NestedList::parse_value(chars, cursor)?);
+                    match chars.get(*cursor) {
+                        Some(',') => *cursor += 1,
+                        Some(']') => {
+                            *cursor += 1;
+                            return Ok(NestedList::List(items));
+                        }
+                        Some(c) => return Err(format!("unexpected '{}' at {}", c, *cursor)),
+                        None => return Err(String::from("unterminated list")),
+                    }
+                }
+            }
+            Some(c) if c.is_ascii_digit() || *c == '-' => {
+                let start = *cursor;
+                *cursor += 1;
+                while chars.get(*cursor).is_some_and(|c| c.is_ascii_digit()) {
+                    *cursor += 1;
+                }
+                let token = chars[start..*cursor].iter().collect::<String>();
+                token
+                    .parse::<i64>()
+                    .map(NestedList::Int)
+                    .map_err(|_| format!("bad integer \"{}\"", token))
+            }
+            Some(c) => Err(format!("unexpected '{}' at {}", c, *cursor)),
+            None => Err(String::from("unexpected end of input")),
+        }
+    }
+}
+
+impl FromStr for NestedList {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<Vec<char>>();
+        let mut cursor = 0;
+        let value = NestedList::parse_value(&chars, &mut cursor).map_err(AocError::Format)?;
+        if cursor != chars.len() {
+            return Err(AocError::Format(format!(
+                "unexpected trailing input at {}",
+                cursor
+            )));
+        }
+        Ok(value)
+    }
+}
+
+impl Ord for NestedList {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (NestedList::Int(left), NestedList::Int(right)) => left.cmp(right),
+            (NestedList::List(left), NestedList::List(right)) => left.cmp(right),
+            (NestedList::Int(left), NestedList::List(right)) => {
+                [NestedList::Int(*left)].as_slice().cmp(right.as_slice())
+            }
+            (NestedList::List(left), NestedList::Int(right)) => {
+                left.as_slice().cmp([NestedList::Int(*right)].as_slice())
+            }
+        }
+    }
+}
+
+impl PartialEq for NestedList {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for NestedList {}
+
+impl PartialOrd for NestedList {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for NestedList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NestedList::Int(value) => write!(f, "{}", value),
+            NestedList::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Parses the given nested list, panicking if it is not valid.
+    fn parse(s: &str) -> NestedList {
+        s.parse::<NestedList>().unwrap()
+    }
+
+    /// Tests parsing nested lists, and that they are displayed in the same form.
+    #[test]
+    fn test_nested_list_parse() {
+        let expected = NestedList::List(vec![
+            NestedList::List(vec![NestedList::Int(1)]),
+            NestedList::List(vec![NestedList::Int(2), NestedList::Int(3)]),
+        ]);
+        assert_eq!(expected, parse("[[1],[2,3]]"));
+        for s in [
+            "[]",
+            "[[[]]]",
+            "[1,[2,[3,[4,[5,6,7]]]],8,9]",
+            "[10,-2]",
+            "42",
+        ] {
+            assert_eq!(s, parse(s).to_string());
+        }
+        for s in ["", "[1,2", "[1,,2]", "[1]]", "[a]"] {
+            assert!(
+                matches!(s.parse::<NestedList>(), Err(AocError::Format(_))),
+                "{}",
+                s
+            );
+        }
+    }
+
+    /// Tests comparing the example pairs given in the problem description, of which pairs 1, 2, 4
+    /// and 6 are in the right order.
+    #[test]
+    fn test_nested_list_compare_example_pairs() {
+        let pairs = [
+            ("[1,1,3,1,1]", "[1,1,5,1,1]"),
+            ("[[1],[2,3,4]]", "[[1],4]"),
+            ("[9]", "[[8,7,6]]"),
+            ("[[4,4],4,4]", "[[4,4],4,4,4]"),
+            ("[7,7,7,7]", "[7,7,7]"),
+            ("[]", "[3]"),
+            ("[[[]]]", "[[]]"),
+            ("[1,[2,[3,[4,[5,6,7]]]],8,9]", "[1,[2,[3,[4,[5,6,0]]]],8,9]"),
+        ];
+        let in_order = pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (left, right))| parse(left) < parse(right))
+            .map(|(i, _)| i + 1)
+            .collect::<Vec<usize>>();
+        assert_eq!(vec![1, 2, 4, 6], in_order);
+        assert_eq!(parse("[[1]]"), parse("[1]"));
+        assert_ne!(parse("[[1]]"), parse("[1,1]"));
+    }
+
+    /// Tests sorting a small set of packets.
+    #[test]
+    fn test_nested_list_sort() {
+        let mut packets = ["[[2]]", "[3]", "[]", "[[1],4]", "[[]]", "[1,1,3]", "[[6]]"]
+            .iter()
+            .map(|s| parse(s))
+            .collect::<Vec<NestedList>>();
+        packets.sort();
+        let sorted = packets
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<String>>();
+        let expected = ["[]", "[[]]", "[1,1,3]", "[[1],4]", "[[2]]", "[3]", "[[6]]"];
+        assert_eq!(expected.to_vec(), sorted);
+    }
+}
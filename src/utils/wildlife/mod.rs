@@ -1,3 +1,6 @@
 mod monkey;
 
-pub use self::monkey::{Monkey, Operation};
+pub use self::monkey::{
+    busiest_monkeys, monkey_business, simulate_rounds, supermodulo, validate_targets, Monkey,
+    Operation,
+};
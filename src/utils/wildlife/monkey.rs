@@ -1,5 +1,7 @@
 use std::collections::VecDeque;
 
+use crate::utils::parsing::AocError;
+
 /// Represents a single monkey.
 #[derive(Clone)]
 pub struct Monkey {
@@ -30,23 +32,83 @@ impl Monkey {
         }
     }
 
+    /// Creates a new monkey, with items inspected set to 0. Returns an error if the divisor is zero,
+    /// since the monkey would not be able to test the worry level of its items. An error is also
+    /// returned if the operation discards the worry level (multiplying by or raising to the power of
+    /// zero), or if the monkey throws to the same monkey whether the test passes or fails.
+    pub fn try_new(
+        items: VecDeque<u64>,
+        op: Operation,
+        divisor: u64,
+        true_monkey: usize,
+        false_monkey: usize,
+    ) -> Result<Self, AocError> {
+        if divisor == 0 {
+            return Err(AocError::Format(String::from(
+                "monkey test divisor must be non-zero",
+            )));
+        }
+        if matches!(
+            op,
+            Operation::Mult { value: 0 } | Operation::Pow { value: 0 }
+        ) {
+            return Err(AocError::Format(format!(
+                "monkey operation {:?} discards the worry level",
+                op
+            )));
+        }
+        if true_monkey == false_monkey {
+            return Err(AocError::Format(format!(
+                "monkey throws to monkey {} whether the test passes or fails",
+                true_monkey
+            )));
+        }
+        Ok(Monkey::new(items, op, divisor, true_monkey, false_monkey))
+    }
+
     /// Adds the item to the end of the monkey's current items.
     pub fn give_item(&mut self, item: u64) {
         self.items.push_back(item);
     }
 
+    /// Gets the worry levels of the items currently held by the monkey, in the order they will be
+    /// inspected.
+    pub fn get_items(&self) -> &VecDeque<u64> {
+        &self.items
+    }
+
+    /// Gets the operation the monkey applies to the worry level of each item it inspects.
+    pub fn get_operation(&self) -> Operation {
+        self.op
+    }
+
     /// Gets the divisor of the monkey used to check item worry levels.
     pub fn get_divisor(&self) -> u64 {
         self.divisor
     }
 
+    /// Gets the index of the monkey that items are thrown to when the divisor test passes.
+    pub fn get_true_monkey(&self) -> usize {
+        self.true_monkey
+    }
+
+    /// Gets the index of the monkey that items are thrown to when the divisor test fails.
+    pub fn get_false_monkey(&self) -> usize {
+        self.false_monkey
+    }
+
     /// Gets the number of items the monkey has inspected.
     pub fn get_items_inspected(&self) -> u64 {
         self.items_inspected
     }
 
-    /// Monkey inspects and throws each of its items in order.
-    pub fn inspect_and_throw(&mut self, reduce_worry: bool, supermodulo: u64) -> Vec<(usize, u64)> {
+    /// Monkey inspects and throws each of its items in order. If a worry reduction divisor is
+    /// given, the worry level of each item is divided by it after the item is inspected.
+    pub fn inspect_and_throw(
+        &mut self,
+        reduce: Option<u64>,
+        supermodulo: u64,
+    ) -> Vec<(usize, u64)> {
         let mut thrown_items: Vec<(usize, u64)> = vec![];
         loop {
             if self.items.is_empty() {
@@ -60,8 +122,8 @@ impl Monkey {
                 Operation::Pow { value } => self.items[0] = self.items[0].pow(value),
             }
             // Reduce the worry
-            if reduce_worry {
-                self.items[0] /= 3;
+            if let Some(divisor) = reduce {
+                self.items[0] /= divisor;
             }
             // Apply the supermodulo to reduce the item worry to stop it becoming too large
             self.items[0] %= supermodulo;
@@ -70,7 +132,7 @@ impl Monkey {
             }
             // Check for throw
             let new_monkey = {
-                if self.items[0] % self.divisor == 0 {
+                if self.items[0].is_multiple_of(self.divisor) {
                     self.true_monkey
                 } else {
                     self.false_monkey
@@ -82,10 +144,195 @@ impl Monkey {
     }
 }
 
+/// Checks that each monkey throws its items to another monkey in the group. Returns an error if a
+/// monkey would throw to itself or to a monkey index outside of the group.
+pub fn validate_targets(monkeys: &[Monkey]) -> Result<(), AocError> {
+    for (i, monkey) in monkeys.iter().enumerate() {
+        for target in [monkey.get_true_monkey(), monkey.get_false_monkey()] {
+            if target == i {
+                return Err(AocError::Format(format!("monkey {} throws to itself", i)));
+            }
+            if target >= monkeys.len() {
+                return Err(AocError::Format(format!(
+                    "monkey {} throws to monkey {}, but there are only {} monkeys",
+                    i,
+                    target,
+                    monkeys.len()
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Calculates the supermodulo for the monkeys, which is the product of their test divisors. Reducing
+/// item worry levels by the supermodulo does not change which monkey each item is thrown to.
+pub fn supermodulo(monkeys: &[Monkey]) -> u64 {
+    monkeys.iter().map(|m| m.get_divisor()).product()
+}
+
+/// Simulates the given number of rounds of monkey business, updating the monkeys in place. In each
+/// round, every monkey in turn inspects and throws all of its items. If a worry reduction divisor
+/// is given, item worry levels are divided by it after each inspection. Item worry levels are kept
+/// in check by reducing them by the supermodulo (see supermodulo()).
+pub fn simulate_rounds(
+    monkeys: &mut [Monkey],
+    rounds: usize,
+    reduce: Option<u64>,
+    supermodulo: u64,
+) {
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            // Get the items thrown by the current monkey then give them to the receiving monkey
+            let thrown_items = monkeys[i].inspect_and_throw(reduce, supermodulo);
+            for (new_monkey, item) in thrown_items {
+                monkeys[new_monkey].give_item(item);
+            }
+        }
+    }
+}
+
+/// Calculates the level of monkey business, which is the product of the number of items inspected
+/// by the two busiest monkeys.
+pub fn monkey_business(monkeys: &[Monkey]) -> u64 {
+    busiest_monkeys(monkeys, 2)
+        .iter()
+        .map(|(_, inspected)| inspected)
+        .product()
+}
+
+/// Gets the indices of the n busiest monkeys along with the number of items each has inspected.
+/// Monkeys are sorted by the number of items inspected in descending order, with ties broken by
+/// the lower monkey index coming first.
+pub fn busiest_monkeys(monkeys: &[Monkey], n: usize) -> Vec<(usize, u64)> {
+    let mut counts = monkeys
+        .iter()
+        .map(|m| m.get_items_inspected())
+        .enumerate()
+        .collect::<Vec<(usize, u64)>>();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    counts.truncate(n);
+    counts
+}
+
 /// Represents an operator performed on the worry level of items by monkey.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Operation {
     Add { value: u64 },
     Mult { value: u64 },
     Pow { value: u32 }, // value is u32 here to allow use as exponent in .pow() method
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Creates a monkey that has inspected the given number of items.
+    fn monkey_with_inspected(items_inspected: u64) -> Monkey {
+        let mut monkey = Monkey::new(VecDeque::new(), Operation::Add { value: 1 }, 2, 0, 0);
+        monkey.items_inspected = items_inspected;
+        monkey
+    }
+
+    /// Tests that the busiest monkeys are sorted by items inspected, with ties broken by index.
+    #[test]
+    fn test_busiest_monkeys_tie_break() {
+        let monkeys = [4, 9, 2, 9]
+            .iter()
+            .map(|n| monkey_with_inspected(*n))
+            .collect::<Vec<Monkey>>();
+        assert_eq!(vec![(1, 9), (3, 9)], busiest_monkeys(&monkeys, 2));
+        assert_eq!(4, busiest_monkeys(&monkeys, 10).len());
+    }
+
+    /// Creates the monkeys from the example given in the problem description.
+    fn example_monkeys() -> Vec<Monkey> {
+        vec![
+            Monkey::new(
+                VecDeque::from([79, 98]),
+                Operation::Mult { value: 19 },
+                23,
+                2,
+                3,
+            ),
+            Monkey::new(
+                VecDeque::from([54, 65, 75, 74]),
+                Operation::Add { value: 6 },
+                19,
+                2,
+                0,
+            ),
+            Monkey::new(
+                VecDeque::from([79, 60, 97]),
+                Operation::Pow { value: 2 },
+                13,
+                1,
+                3,
+            ),
+            Monkey::new(VecDeque::from([74]), Operation::Add { value: 3 }, 17, 0, 1),
+        ]
+    }
+
+    /// Tests the number of items inspected by each of the example monkeys after 1 round and after 20
+    /// rounds with worry reduction, along with the resulting level of monkey business.
+    #[test]
+    fn test_simulate_rounds_example() {
+        let mut monkeys = example_monkeys();
+        let supermodulo = supermodulo(&monkeys);
+        assert_eq!(96577, supermodulo);
+        simulate_rounds(&mut monkeys, 1, Some(3), supermodulo);
+        let inspected = monkeys
+            .iter()
+            .map(|m| m.get_items_inspected())
+            .collect::<Vec<u64>>();
+        assert_eq!(vec![2, 4, 3, 5], inspected);
+        simulate_rounds(&mut monkeys, 19, Some(3), supermodulo);
+        let inspected = monkeys
+            .iter()
+            .map(|m| m.get_items_inspected())
+            .collect::<Vec<u64>>();
+        assert_eq!(vec![101, 95, 7, 105], inspected);
+        assert_eq!(10605, monkey_business(&monkeys));
+    }
+
+    /// Tests that a monkey cannot be created with a zero divisor.
+    #[test]
+    fn test_monkey_try_new_zero_divisor() {
+        let op = Operation::Mult { value: 19 };
+        let result = Monkey::try_new(VecDeque::from([79, 98]), op, 0, 2, 3);
+        assert!(matches!(result, Err(AocError::Format(_))));
+        let monkey = Monkey::try_new(VecDeque::from([79, 98]), op, 23, 2, 3).unwrap();
+        assert_eq!(23, monkey.get_divisor());
+    }
+
+    /// Tests that a monkey cannot be created with an operation that discards the worry level, or
+    /// with the same monkey thrown to whether the test passes or fails.
+    #[test]
+    fn test_monkey_try_new_meaningless_values() {
+        for op in [Operation::Mult { value: 0 }, Operation::Pow { value: 0 }] {
+            let result = Monkey::try_new(VecDeque::from([79, 98]), op, 23, 2, 3);
+            assert!(matches!(result, Err(AocError::Format(_))));
+        }
+        let op = Operation::Mult { value: 19 };
+        let result = Monkey::try_new(VecDeque::from([79, 98]), op, 23, 2, 2);
+        assert!(matches!(result, Err(AocError::Format(_))));
+    }
+
+    /// Tests that the targets of the example monkeys are valid, and that a monkey throwing to itself
+    /// or to a monkey outside of the group is rejected.
+    #[test]
+    fn test_validate_targets() {
+        let mut monkeys = example_monkeys();
+        assert!(validate_targets(&monkeys).is_ok());
+        monkeys[1].true_monkey = 1;
+        assert!(matches!(
+            validate_targets(&monkeys),
+            Err(AocError::Format(_))
+        ));
+        monkeys[1].true_monkey = 4;
+        assert!(matches!(
+            validate_targets(&monkeys),
+            Err(AocError::Format(_))
+        ));
+    }
+}
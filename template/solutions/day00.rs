@@ -0,0 +1,28 @@
+/// Parsed form of the AOC 2022 Day 00 input used by the solver functions.
+pub type ProblemInput = String;
+
+/// Parses the raw AOC 2022 Day 00 input in the format required by the solver functions.
+/// Returned value is ###.
+pub fn parse_input(_raw_input: &str) -> ProblemInput {
+    unimplemented!();
+}
+
+/// Solves both parts of AOC 2022 Day 00 using the raw input given as a string, without needing
+/// access to the filesystem. Returned value is the tuple of the Part 1 and Part 2 solutions.
+pub fn solve_day00_from_str(input: &str) -> (String, String) {
+    let input = parse_input(input);
+    (
+        solve_part1(&input).to_string(),
+        solve_part2(&input).to_string(),
+    )
+}
+
+/// Solves AOC 2022 Day 00 Part 1 // ###
+pub fn solve_part1(_input: &ProblemInput) -> String {
+    unimplemented!();
+}
+
+/// Solves AOC 2022 Day 00 Part 2 // ###
+pub fn solve_part2(_input: &ProblemInput) -> String {
+    unimplemented!();
+}